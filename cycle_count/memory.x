@@ -0,0 +1,6 @@
+/* Texas Instruments TM4C123GH6PM, as on the Tiva-C Launchpad */
+MEMORY
+{
+  FLASH : ORIGIN = 0x00000000, LENGTH = 256K
+  RAM : ORIGIN = 0x20000000, LENGTH = 32K
+}
@@ -0,0 +1,165 @@
+//! Cycle counting firmware for vga-framebuffer
+//!
+//! Fills the screen with coloured text, runs `isr_sol` for two whole frames
+//! and uses the DWT cycle counter to time every visible scan-line. The
+//! results are left in the `RESULTS` static and the firmware then stops at a
+//! breakpoint, so they can be read with a debugger:
+//!
+//! ```text
+//! $ cargo build --release --features compact_rgb
+//! $ openocd -f board/ek-tm4c123gxl.cfg &
+//! $ arm-none-eabi-gdb target/thumbv7em-none-eabihf/release/cycle-count
+//! (gdb) target extended-remote :3333
+//! (gdb) load
+//! (gdb) continue
+//! (gdb) print RESULTS
+//! ```
+//!
+//! The TM4C123 comes out of reset running at 16 MHz from its internal
+//! oscillator, which needs no flash wait states. Monotron runs at 80 MHz,
+//! where the flash needs wait states (partly hidden by the prefetch buffer).
+//! For figures which match that, set up the PLL for 80 MHz at the top of
+//! `main` (e.g. with `tm4c123x-hal`) before taking the measurements.
+
+#![no_std]
+#![no_main]
+
+extern crate cortex_m;
+extern crate cortex_m_rt;
+extern crate panic_halt;
+extern crate vga_framebuffer;
+
+use core::fmt::Write;
+use core::ptr;
+
+use cortex_m::peripheral::DWT;
+use cortex_m_rt::entry;
+use vga_framebuffer::{Attr, Colour, FrameBuffer, Hardware, ModeInfo, XRGBColour};
+
+// ***************************************************************************
+//
+// Constants
+//
+// ***************************************************************************
+
+/// How many times to call `isr_sol` - a little over two frames.
+const NUM_CALLS: usize = 1300;
+
+/// The text to fill the screen with. The colours change each time it is
+/// written, and every other time it is bold.
+const TEXT: &str = "The quick brown fox jumps over the lazy dog. 0123456789 ";
+
+// ***************************************************************************
+//
+// Types
+//
+// ***************************************************************************
+
+/// What we measured. All figures are in CPU cycles per visible scan-line,
+/// including the call to `isr_sol` and its calls to `write_pixels`.
+#[repr(C)]
+pub struct Results {
+    /// How many visible scan-lines were timed
+    lines: u32,
+    /// The total for all the timed scan-lines
+    total: u32,
+    /// The quickest scan-line
+    min: u32,
+    /// The slowest scan-line
+    max: u32,
+}
+
+/// Hardware which throws the pixels away, with a volatile write standing in
+/// for the write to the SPI FIFO.
+struct Sink;
+
+// ***************************************************************************
+//
+// Static Data
+//
+// ***************************************************************************
+
+#[no_mangle]
+pub static mut RESULTS: Results = Results {
+    lines: 0,
+    total: 0,
+    min: u32::MAX,
+    max: 0,
+};
+
+static mut PIXELS: u32 = 0;
+
+// ***************************************************************************
+//
+// Impl for Types
+//
+// ***************************************************************************
+
+impl Hardware for Sink {
+    fn configure(&mut self, _mode_info: &ModeInfo) {}
+
+    fn vsync_on(&mut self) {}
+
+    fn vsync_off(&mut self) {}
+
+    fn write_pixels(&mut self, xrgb: XRGBColour) {
+        unsafe { ptr::write_volatile(ptr::addr_of_mut!(PIXELS), xrgb.0) };
+    }
+}
+
+// ***************************************************************************
+//
+// Functions
+//
+// ***************************************************************************
+
+#[entry]
+fn main() -> ! {
+    let mut cp = cortex_m::Peripherals::take().unwrap();
+    cp.DCB.enable_trace();
+    cp.DWT.enable_cycle_counter();
+
+    let mut fb = FrameBuffer::new();
+    fb.init(Sink);
+    let colours = [
+        Colour::White,
+        Colour::Red,
+        Colour::Yellow,
+        Colour::Green,
+        Colour::Cyan,
+        Colour::Blue,
+        Colour::Magenta,
+    ];
+    for (index, fg) in colours.iter().cycle().take(32).enumerate() {
+        let mut attr = Attr::new(*fg, Colour::Black);
+        attr.set_bold(index % 2 == 1);
+        fb.set_attr(attr);
+        let _ = fb.write_str(TEXT);
+    }
+
+    let mut results = Results {
+        lines: 0,
+        total: 0,
+        min: u32::MAX,
+        max: 0,
+    };
+    for _ in 0..NUM_CALLS {
+        let visible = fb.line().is_some();
+        let start = DWT::cycle_count();
+        fb.isr_sol();
+        let cycles = DWT::cycle_count().wrapping_sub(start);
+        if visible {
+            results.lines += 1;
+            results.total += cycles;
+            results.min = results.min.min(cycles);
+            results.max = results.max.max(cycles);
+        }
+    }
+    unsafe { ptr::write_volatile(ptr::addr_of_mut!(RESULTS), results) };
+
+    loop {
+        cortex_m::asm::bkpt();
+    }
+}
+
+// End of file
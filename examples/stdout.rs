@@ -1,7 +1,10 @@
 extern crate term;
 extern crate vga_framebuffer;
 
-use vga_framebuffer::{AsciiConsole, Attr, Col, Colour, ModeInfo, Position, Row, XRGBColour};
+use vga_framebuffer::image::{self, Dither, Image};
+use vga_framebuffer::{
+    AsciiConsole, Attr, BitOrder, Col, Colour, Mode2Layout, ModeInfo, Position, Row, XRGBColour,
+};
 
 mod rust_logo;
 
@@ -10,7 +13,7 @@ struct Dummy {
     output: Box<dyn term::Terminal<Output = std::io::Stdout>>,
 }
 
-impl<'a> vga_framebuffer::Hardware for &'a mut Dummy {
+impl vga_framebuffer::Hardware for &mut Dummy {
     fn configure(&mut self, mode_info: &ModeInfo) {
         println!("{:?}", mode_info);
     }
@@ -80,8 +83,8 @@ fn main() {
         output: term::stdout().unwrap(),
     };
     let mut mode2_buffer = vec![
-        0xAAu8;
-        ((7 + vga_framebuffer::MODE2_WIDTH_PIXELS) / 8)
+        0u8;
+        vga_framebuffer::MODE2_WIDTH_PIXELS.div_ceil(8)
             * vga_framebuffer::MODE2_USABLE_LINES
     ];
     let mut fb = vga_framebuffer::FrameBuffer::new();
@@ -112,25 +115,57 @@ fn main() {
         wheel.next();
     }
 
-    for (src, dest) in rust_logo::RUST_LOGO_DATA
-        .iter()
-        .zip(mode2_buffer.iter_mut())
-    {
-        // Our source is an X-Bitmap, which puts the pixels in LSB-first order.
-        // We need MSB first order for Monotron.
-        *dest = flip_byte(*src);
-    }
-
     // Attach a graphical buffer at a scan-line. It is interpreted as
     // being a grid 48 bytes wide and as long as given. Each line
     // is output twice. We've attached it to the first scan-line.
-    fb.mode2(&mut mode2_buffer[..], 0);
+    fb.mode2(&rust_logo::RUST_LOGO_DATA, 0);
+    // Our source is an X-Bitmap, which puts the pixels in LSB-first order,
+    // so say so rather than copying it.
+    fb.mode2_layout(Mode2Layout {
+        bit_order: BitOrder::LsbFirst,
+        ..Mode2Layout::default()
+    });
+
+    for _ in 0..628 {
+        fb.isr_sol();
+    }
+
+    fb.mode2_release();
+
+    // To draw on it, we need it in a buffer of our own, in the layout
+    // `Mode2Canvas` uses. An XBM file would be C source, so make one and
+    // let the `image` module convert it.
+    let xbm_bytes: Vec<String> = rust_logo::RUST_LOGO_DATA
+        .iter()
+        .map(|b| format!("0x{:02x}", b))
+        .collect();
+    let xbm = format!(
+        "#define rust_logo_width {}\n#define rust_logo_height {}\n\
+         static char rust_logo_bits[] = {{ {} }};\n",
+        vga_framebuffer::MODE2_WIDTH_PIXELS,
+        vga_framebuffer::MODE2_USABLE_LINES,
+        xbm_bytes.join(", ")
+    );
+    let logo = Image::parse(xbm.as_bytes()).unwrap();
+    image::to_mode2(&logo, Dither::Threshold, &mut mode2_buffer).unwrap();
+
+    let mut canvas = vga_framebuffer::Mode2Canvas::new(&mut mode2_buffer);
+    let (width, height) = (canvas.width() as isize, canvas.height() as isize);
+    // Set bits in an XBM are black, so the logo has come out dark on a
+    // light background. Invert it to match the one above.
+    canvas.fill_rect(0, 0, width, height, vga_framebuffer::Ink::Xor);
+
+    // Draw a frame around the logo
+    canvas.rect(0, 0, width, height, vga_framebuffer::Ink::On);
+    canvas.rect(2, 2, width - 4, height - 4, vga_framebuffer::Ink::On);
+
+    fb.mode2(canvas.as_slice(), 0);
 
     for _ in 0..628 {
         fb.isr_sol();
     }
 
-    let _ = fb.mode2_release();
+    fb.mode2_release();
 
     fb.clear();
 
@@ -148,6 +183,26 @@ fn main() {
     fb.set_custom_font(None);
     fb.set_cursor_visible(true);
 
+    // Switching code page changes how Unicode is mapped, so pick the
+    // matching font too.
+    fb.set_code_page(vga_framebuffer::CodePage::Koi8R);
+    fb.set_custom_font(Some(&vga_framebuffer::koi8_r::FONT_DATA));
+    fb.clear();
+    writeln!(fb, "Привет, мир! This is KOI8-R").unwrap();
+    // Characters KOI8-R doesn't have are transliterated.
+    writeln!(
+        fb,
+        "Nai\u{0308}ve \u{2013} \u{201c}caf\u{00e9}\u{201d}\u{2026}"
+    )
+    .unwrap();
+
+    for _ in 0..628 {
+        fb.isr_sol();
+    }
+
+    fb.set_code_page(vga_framebuffer::CodePage::Cp850);
+    fb.set_custom_font(None);
+
     fb.clear();
     // You have to put double-height text in twice, once for the top line and once for the bottom line.
     writeln!(fb, "\u{001b}^\u{001b}k\u{001b}RThis is double height text").unwrap();
@@ -158,9 +213,3 @@ fn main() {
         fb.isr_sol();
     }
 }
-
-fn flip_byte(mut b: u8) -> u8 {
-    b = (b & 0xF0) >> 4 | (b & 0x0F) << 4;
-    b = (b & 0xCC) >> 2 | (b & 0x33) << 2;
-    (b & 0xAA) >> 1 | (b & 0x55) << 1
-}
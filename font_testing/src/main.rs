@@ -0,0 +1,432 @@
+//! Font conversion and inspection tool for vga-framebuffer
+//!
+//! Reads an 8-pixel wide, 256 glyph bitmap font and either previews it as
+//! ASCII art, or converts it into the `pub static FONT_DATA` Rust source
+//! layout used by `vga_framebuffer::freebsd_cp850`.
+//!
+//! Three input formats are understood:
+//!
+//! * Raw binary (e.g. `cp850-8x16`) - 256 glyphs, one byte per row.
+//! * FreeBSD syscons `.fnt` files, which are the same data uuencoded.
+//! * Rust source in the `FONT_DATA` layout, so an edited font can be
+//!   previewed or round-tripped without going back to the original file.
+//!
+//! The glyph height is worked out from the size of the data. Fonts shorter
+//! than 16 rows (e.g. `cp850-8x8`) are padded with blank rows at the bottom
+//! when converted, as the crate always uses 16 bytes per glyph.
+//!
+//! Glyphs are labelled with their CP850 `Char` name, or with their Unicode
+//! symbol if another code page is given with `--code-page`.
+//!
+//! The `bold` command converts a font in the same way, but with each row ORed
+//! with itself shifted one pixel to the right. That gives the bold font the
+//! crate draws bold text with (see `freebsd_cp850_bold`), so it needn't be
+//! worked out for every octet at render time.
+//!
+//! Fonts are usually under a licence which must be kept with them, so any
+//! comment at the top of the input (the `#` lines before `begin` in a `.fnt`
+//! file, or the `///` lines before `FONT_DATA` in a `.rs` file) is copied to
+//! the top of the converted source.
+
+extern crate vga_framebuffer;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::process;
+
+use vga_framebuffer::{Char, CodePage};
+
+// ***************************************************************************
+//
+// Constants
+//
+// ***************************************************************************
+
+/// How many glyphs in a font
+const NUM_GLYPHS: usize = 256;
+
+/// How many rows each glyph has in `FONT_DATA`
+const FONT_DATA_HEIGHT: usize = 16;
+
+const USAGE: &str = "Usage:
+    font-tool [--code-page <page>] show <font> [<first>[..<last>]]
+        Print each glyph as ASCII art, with its index and name.
+    font-tool [--code-page <page>] convert <font> [<output.rs>]
+        Write the font as a `pub static FONT_DATA` Rust source file.
+    font-tool [--code-page <page>] bold <font> [<output.rs>]
+        As convert, but make each glyph bold.
+    font-tool [--code-page <page>] names
+        List the name for each index.
+
+<font> is a raw binary font, a uuencoded FreeBSD .fnt file or a .rs file
+in the FONT_DATA layout.
+
+<page> is one of cp850 (the default), cp437, iso-8859-1, iso-8859-15 or
+koi8-r.";
+
+// ***************************************************************************
+//
+// Types
+//
+// ***************************************************************************
+
+/// An 8-pixel wide bitmap font with 256 glyphs.
+struct Font {
+    /// Number of rows (bytes) per glyph
+    height: usize,
+    /// `height` bytes per glyph, MSB is the left-most pixel
+    data: Vec<u8>,
+    /// The comment (e.g. copyright and licence) from the top of the file
+    header: Vec<String>,
+    /// Whether `embolden` has been called
+    bold: bool,
+}
+
+// ***************************************************************************
+//
+// Impl for Types
+//
+// ***************************************************************************
+
+impl Font {
+    /// Build a font from raw data, working out the glyph height.
+    fn from_data(data: Vec<u8>) -> Result<Font, String> {
+        let height = data.len() / NUM_GLYPHS;
+        if height * NUM_GLYPHS != data.len() || height == 0 || height > FONT_DATA_HEIGHT {
+            return Err(format!(
+                "font is {} bytes - expected 256 glyphs of 1 to {} rows each",
+                data.len(),
+                FONT_DATA_HEIGHT
+            ));
+        }
+        Ok(Font {
+            height,
+            data,
+            header: Vec::new(),
+            bold: false,
+        })
+    }
+
+    /// Load a font, choosing the format based on the file contents and name.
+    fn load(path: &str) -> Result<Font, String> {
+        let contents = fs::read(path).map_err(|e| format!("can't read {}: {}", path, e))?;
+        let text = String::from_utf8_lossy(&contents);
+        let uuencoded = text
+            .lines()
+            .find(|line| !line.starts_with('#'))
+            .is_some_and(|line| line.starts_with("begin "));
+        let (data, header) = if uuencoded {
+            (uudecode(&text)?, fnt_header(&text))
+        } else if path.ends_with(".rs") {
+            (parse_rust(&text)?, rust_header(&text))
+        } else {
+            (contents, Vec::new())
+        };
+        let mut font = Font::from_data(data)?;
+        font.header = header;
+        Ok(font)
+    }
+
+    /// Make every glyph bold, by smearing each row one pixel to the right.
+    fn embolden(&mut self) {
+        for row in self.data.iter_mut() {
+            *row |= *row >> 1;
+        }
+        self.bold = true;
+    }
+
+    /// Get the rows for the given glyph.
+    fn glyph(&self, index: usize) -> &[u8] {
+        &self.data[index * self.height..(index + 1) * self.height]
+    }
+}
+
+// ***************************************************************************
+//
+// Functions
+//
+// ***************************************************************************
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let mut code_page = Ok(CodePage::Cp850);
+    if args.first().map(|s| s.as_str()) == Some("--code-page") && args.len() >= 2 {
+        code_page = parse_code_page(&args[1]);
+        args.drain(0..2);
+    }
+    let result = code_page.and_then(|cp| match args.first().map(|s| s.as_str()) {
+        Some("show") if args.len() == 2 || args.len() == 3 => {
+            show(cp, &args[1], args.get(2).map(|s| s.as_str()))
+        }
+        Some("convert") if args.len() == 2 || args.len() == 3 => {
+            convert(cp, &args[1], args.get(2).map(|s| s.as_str()), false)
+        }
+        Some("bold") if args.len() == 2 || args.len() == 3 => {
+            convert(cp, &args[1], args.get(2).map(|s| s.as_str()), true)
+        }
+        Some("names") if args.len() == 1 => names(cp),
+        _ => Err(USAGE.to_owned()),
+    });
+    if let Err(msg) = result {
+        eprintln!("{}", msg);
+        process::exit(1);
+    }
+}
+
+/// Print some or all of the glyphs in a font as ASCII art.
+fn show(code_page: CodePage, path: &str, range: Option<&str>) -> Result<(), String> {
+    let font = Font::load(path)?;
+    let (first, last) = match range {
+        Some(r) => parse_range(r)?,
+        None => (0, NUM_GLYPHS - 1),
+    };
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for index in first..=last {
+        writeln!(
+            out,
+            "Glyph {} (0x{:02x}) = {}",
+            index,
+            index,
+            glyph_name(code_page, index)
+        )
+        .map_err(|e| e.to_string())?;
+        for row in font.glyph(index) {
+            let line: String = (0..8)
+                .map(|bit| if row & (0x80 >> bit) != 0 { 'X' } else { '.' })
+                .collect();
+            writeln!(out, "    {}", line).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Convert a font to Rust source, writing to a file or to stdout.
+fn convert(
+    code_page: CodePage,
+    path: &str,
+    output: Option<&str>,
+    bold: bool,
+) -> Result<(), String> {
+    let mut font = Font::load(path)?;
+    if bold {
+        font.embolden();
+    }
+    let source = render_rust(code_page, &font, path);
+    match output {
+        Some(output) => {
+            fs::write(output, source).map_err(|e| format!("can't write {}: {}", output, e))
+        }
+        None => io::stdout()
+            .write_all(source.as_bytes())
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// List the name for every index.
+fn names(code_page: CodePage) -> Result<(), String> {
+    for index in 0..NUM_GLYPHS {
+        println!(
+            "{:3} 0x{:02x} {}",
+            index,
+            index,
+            glyph_name(code_page, index)
+        );
+    }
+    Ok(())
+}
+
+/// Describe a glyph - by `Char` name for CP850, or by Unicode symbol.
+fn glyph_name(code_page: CodePage, index: usize) -> String {
+    let glyph = Char::from_byte(index as u8);
+    match code_page {
+        CodePage::Cp850 => format!("Char::{:?}", glyph),
+        _ => format!("{:?}", code_page.to_char(glyph)),
+    }
+}
+
+/// Parse a code page name, as given to `--code-page`.
+fn parse_code_page(name: &str) -> Result<CodePage, String> {
+    match name.to_lowercase().as_str() {
+        "cp850" => Ok(CodePage::Cp850),
+        "cp437" => Ok(CodePage::Cp437),
+        "iso-8859-1" | "latin-1" => Ok(CodePage::Iso8859_1),
+        "iso-8859-15" | "latin-9" => Ok(CodePage::Iso8859_15),
+        "koi8-r" => Ok(CodePage::Koi8R),
+        _ => Err(format!("unknown code page {:?}", name)),
+    }
+}
+
+/// Parse `N` or `N..M` (inclusive), where each is decimal or 0x hex.
+fn parse_range(range: &str) -> Result<(usize, usize), String> {
+    let mut parts = range.splitn(2, "..");
+    let first = parse_number(parts.next().unwrap_or(""))?;
+    let last = match parts.next() {
+        Some(last) => parse_number(last)?,
+        None => first,
+    };
+    if first > last || last >= NUM_GLYPHS {
+        return Err(format!("bad glyph range {:?}", range));
+    }
+    Ok((first, last))
+}
+
+/// Parse an integer literal as it might appear in Rust source.
+fn parse_number(text: &str) -> Result<usize, String> {
+    let text: String = text.trim().chars().filter(|&c| c != '_').collect();
+    let result = if let Some(bin) = text.strip_prefix("0b") {
+        usize::from_str_radix(bin, 2)
+    } else if let Some(hex) = text.strip_prefix("0x") {
+        usize::from_str_radix(hex, 16)
+    } else {
+        text.parse()
+    };
+    result.map_err(|_| format!("can't parse number {:?}", text))
+}
+
+/// Extract the bytes from a `FONT_DATA` array in Rust source.
+fn parse_rust(source: &str) -> Result<Vec<u8>, String> {
+    let start = source
+        .find("FONT_DATA")
+        .and_then(|idx| source[idx..].find("= [").map(|offset| idx + offset + 3))
+        .ok_or_else(|| "can't find `FONT_DATA: [...] = [` in source".to_owned())?;
+    let mut data = Vec::new();
+    for line in source[start..].lines() {
+        // Strip comments, such as the `// Char::Xxx` markers
+        let line = line.split("//").next().unwrap_or("");
+        let (line, finished) = match line.find(']') {
+            Some(idx) => (&line[..idx], true),
+            None => (line, false),
+        };
+        for item in line.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            let value = parse_number(item.trim_end_matches("u8"))?;
+            if value > 0xFF {
+                return Err(format!("value {:?} doesn't fit in a u8", item));
+            }
+            data.push(value as u8);
+        }
+        if finished {
+            return Ok(data);
+        }
+    }
+    Err("unterminated FONT_DATA array".to_owned())
+}
+
+/// Get the `#` comment lines before the `begin` line of a `.fnt` file,
+/// leaving out the `$FreeBSD$` version control tag.
+fn fnt_header(text: &str) -> Vec<String> {
+    text.lines()
+        .take_while(|line| line.starts_with('#'))
+        .map(|line| line.trim_start_matches('#'))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .filter(|line| !line.starts_with("$FreeBSD"))
+        .map(|line| line.to_owned())
+        .collect()
+}
+
+/// Get the `///` doc comment lines above `FONT_DATA` in a `.rs` file.
+fn rust_header(text: &str) -> Vec<String> {
+    text.lines()
+        .take_while(|line| !line.contains("FONT_DATA"))
+        .filter_map(|line| line.trim_start().strip_prefix("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).to_owned())
+        .collect()
+}
+
+/// Decode a uuencoded file, as used for FreeBSD syscons fonts. Any `#`
+/// comment lines before the `begin` line are skipped.
+fn uudecode(text: &str) -> Result<Vec<u8>, String> {
+    let mut data = Vec::new();
+    for line in text.lines().skip_while(|line| line.starts_with('#')).skip(1) {
+        let line = line.as_bytes();
+        if line == b"end" {
+            return Ok(data);
+        }
+        let decode = |c: u8| c.wrapping_sub(b' ') & 0x3F;
+        let length = match line.first() {
+            Some(&c) => decode(c) as usize,
+            None => continue,
+        };
+        let mut bytes = Vec::with_capacity(length + 2);
+        for chunk in line[1..].chunks(4) {
+            let mut group = [0u8; 4];
+            for (dest, src) in group.iter_mut().zip(chunk) {
+                *dest = decode(*src);
+            }
+            bytes.push((group[0] << 2) | (group[1] >> 4));
+            bytes.push((group[1] << 4) | (group[2] >> 2));
+            bytes.push((group[2] << 6) | group[3]);
+        }
+        if bytes.len() < length {
+            return Err(format!(
+                "truncated uuencoded line {:?}",
+                String::from_utf8_lossy(line)
+            ));
+        }
+        data.extend_from_slice(&bytes[..length]);
+    }
+    Err("uuencoded data has no `end` line".to_owned())
+}
+
+/// Render a font in the same layout as `freebsd_cp850.rs`. A `.rs` file's
+/// own doc comment is kept as it is, unless the font has been made bold; any
+/// other file's comment is added below a note saying where the font came
+/// from.
+fn render_rust(code_page: CodePage, font: &Font, source_name: &str) -> String {
+    let mut out = String::new();
+    if source_name.ends_with(".rs") && !font.header.is_empty() && !font.bold {
+        push_comment(&mut out, &font.header);
+        return render_data(out, code_page, font);
+    }
+    match code_page {
+        CodePage::Cp850 => out.push_str(
+            "/// The font data here must be in the same order as the `Char` enum. This\n",
+        ),
+        _ => out.push_str(&format!(
+            "/// The font data here must be in `CodePage::{:?}` order. This\n",
+            code_page
+        )),
+    }
+    out.push_str(&format!(
+        "/// was generated by `font-tool` from `{}`.\n",
+        source_name
+    ));
+    if font.bold {
+        out.push_str("/// Each glyph has been made bold, by ORing each row with itself shifted\n");
+        out.push_str("/// one pixel to the right.\n");
+    }
+    if !font.header.is_empty() {
+        out.push_str("///\n");
+        push_comment(&mut out, &font.header);
+    }
+    render_data(out, code_page, font)
+}
+
+/// Add lines to `out` as a `///` doc comment.
+fn push_comment(out: &mut String, lines: &[String]) {
+    for line in lines {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            out.push_str(&format!("/// {}\n", line));
+        }
+    }
+}
+
+/// Add the `FONT_DATA` array to `out`, below its doc comment.
+fn render_data(mut out: String, code_page: CodePage, font: &Font) -> String {
+    out.push_str("pub static FONT_DATA: [u8; 256 * super::MAX_FONT_HEIGHT] = [\n");
+    for index in 0..NUM_GLYPHS {
+        out.push_str(&format!("    // {}\n", glyph_name(code_page, index)));
+        let glyph = font.glyph(index);
+        for row in 0..FONT_DATA_HEIGHT {
+            let value = glyph.get(row).cloned().unwrap_or(0);
+            out.push_str(&format!("    0b{:04b}_{:04b},\n", value >> 4, value & 0x0F));
+        }
+    }
+    out.push_str("];\n\n// End of file\n");
+    out
+}
+
+// End of file
@@ -0,0 +1,514 @@
+//! Drawing on a Mode 2 buffer.
+//!
+//! A Mode 2 buffer is `MODE0_USABLE_HORIZONTAL_OCTETS` bytes per line, with
+//! the most significant bit of each byte being the left-most pixel.
+//! `Mode2Canvas` wraps such a buffer and lets you draw on it in pixel
+//! co-ordinates, with (0, 0) at the top left. Anything which falls outside
+//! the buffer is clipped, so shapes can hang off the edges.
+//!
+//! Co-ordinates are `isize` so that shapes can start off the top or left
+//! hand side.
+
+use core::ops::RangeInclusive;
+
+use super::{MODE0_USABLE_HORIZONTAL_OCTETS, MODE2_WIDTH_PIXELS};
+
+/// How many pending spans `flood_fill` can remember.
+const FLOOD_STACK_LEN: usize = 128;
+
+/// How to change the pixels being drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Ink {
+    /// Turn the pixels on (foreground colour).
+    On,
+    /// Turn the pixels off (background colour).
+    Off,
+    /// Invert the pixels. Drawing the same thing twice puts the picture
+    /// back as it was.
+    Xor,
+}
+
+/// Returned by `Mode2Canvas::flood_fill` if it ran out of room and couldn't
+/// fill the whole area.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct FillIncomplete;
+
+/// Draws on a Mode 2 buffer. See the module documentation.
+pub struct Mode2Canvas<'a> {
+    buffer: &'a mut [u8],
+    height: usize,
+}
+
+impl<'a> Mode2Canvas<'a> {
+    /// Wrap a Mode 2 buffer. Any partial line at the end of the buffer is
+    /// ignored.
+    pub fn new(buffer: &'a mut [u8]) -> Mode2Canvas<'a> {
+        let height = buffer.len() / MODE0_USABLE_HORIZONTAL_OCTETS;
+        Mode2Canvas { buffer, height }
+    }
+
+    /// Get the buffer, e.g. to pass to `FrameBuffer::mode2`.
+    pub fn as_slice(&self) -> &[u8] {
+        self.buffer
+    }
+
+    /// The width of the canvas, in pixels.
+    pub fn width(&self) -> usize {
+        MODE2_WIDTH_PIXELS
+    }
+
+    /// The height of the canvas, in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Set every pixel on or off.
+    pub fn clear(&mut self, on: bool) {
+        let value = if on { 0xFF } else { 0x00 };
+        for byte in self.buffer.iter_mut() {
+            *byte = value;
+        }
+    }
+
+    /// Is the given pixel on? Pixels outside the canvas are off.
+    pub fn get_pixel(&self, x: isize, y: isize) -> bool {
+        match self.locate(x, y) {
+            Some((idx, mask)) => self.buffer[idx] & mask != 0,
+            None => false,
+        }
+    }
+
+    /// Change one pixel.
+    pub fn set_pixel(&mut self, x: isize, y: isize, ink: Ink) {
+        if let Some((idx, mask)) = self.locate(x, y) {
+            match ink {
+                Ink::On => self.buffer[idx] |= mask,
+                Ink::Off => self.buffer[idx] &= !mask,
+                Ink::Xor => self.buffer[idx] ^= mask,
+            }
+        }
+    }
+
+    /// Draw a straight line between two points (inclusive). There is one
+    /// pixel for each step along whichever axis the line covers most, and
+    /// only the steps which are on the canvas are visited, so the ends can
+    /// be anywhere.
+    pub fn line(&mut self, x0: isize, y0: isize, x1: isize, y1: isize, ink: Ink) {
+        let dx = (x1 as i128 - x0 as i128).abs();
+        let dy = (y1 as i128 - y0 as i128).abs();
+        if dx >= dy {
+            self.line_steps((x0, y0), (x1, y1), false, ink);
+        } else {
+            self.line_steps((y0, x0), (y1, x1), true, ink);
+        }
+    }
+
+    /// Draw the outline of a rectangle, with its top left corner at (x, y).
+    pub fn rect(&mut self, x: isize, y: isize, width: isize, height: isize, ink: Ink) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        let (right, bottom) = (x.saturating_add(width - 1), y.saturating_add(height - 1));
+        // Each pixel is only drawn once, so `Ink::Xor` works
+        self.hline(x, right, y, ink);
+        if bottom != y {
+            self.hline(x, right, bottom, ink);
+        }
+        for row in y.saturating_add(1).max(0)..bottom.min(self.height as isize) {
+            self.set_pixel(x, row, ink);
+            if right != x {
+                self.set_pixel(right, row, ink);
+            }
+        }
+    }
+
+    /// Draw a filled rectangle, with its top left corner at (x, y).
+    pub fn fill_rect(&mut self, x: isize, y: isize, width: isize, height: isize, ink: Ink) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        let right = x.saturating_add(width - 1);
+        let bottom = y.saturating_add(height - 1);
+        for row in y.max(0)..=bottom.min(self.height as isize - 1) {
+            self.hline(x, right, row, ink);
+        }
+    }
+
+    /// Draw the outline of a circle, using the midpoint algorithm.
+    pub fn circle(&mut self, centre_x: isize, centre_y: isize, radius: isize, ink: Ink) {
+        if radius <= 0 {
+            if radius == 0 {
+                self.set_pixel(centre_x, centre_y, ink);
+            }
+            return;
+        }
+        if !self.overlaps(centre_x, centre_y, radius) {
+            return;
+        }
+        // Rather than walk the octant from the start, work out where it
+        // crosses each row which is on the canvas
+        let squared = radius as i128 * radius as i128;
+        let top = (-radius).max(0isize.saturating_sub(centre_y));
+        let bottom = radius.min((self.height as isize - 1).saturating_sub(centre_y));
+        for dy in top..=bottom {
+            let y = centre_y + dy;
+            let dy = dy.unsigned_abs() as i128;
+            // The steep octant has one point on this row...
+            let x = midpoint_x(squared - dy * dy);
+            if x >= dy {
+                self.circle_span(centre_x, y, x..=x, ink);
+            }
+            // ...and the shallow one has every point whose x is `dy`
+            let first = ceil_sqrt(squared - dy * dy - dy);
+            let last = isqrt((squared - dy * dy + dy - 1) as u128) as i128;
+            let last = if x >= dy {
+                last.min(dy - 1)
+            } else {
+                last.min(dy)
+            };
+            self.circle_span(centre_x, y, first..=last, ink);
+        }
+    }
+
+    /// Draw a filled circle.
+    pub fn fill_circle(&mut self, centre_x: isize, centre_y: isize, radius: isize, ink: Ink) {
+        if radius < 0 || !self.overlaps(centre_x, centre_y, radius) {
+            return;
+        }
+        // Only the rows which are on the canvas
+        let top = (-radius).max(0isize.saturating_sub(centre_y));
+        let bottom = radius.min((self.height as isize - 1).saturating_sub(centre_y));
+        for dy in top..=bottom {
+            let squared = (radius as i128 * radius as i128) - (dy as i128 * dy as i128);
+            let half_width = isqrt(squared as u128) as isize;
+            self.hline(
+                centre_x.saturating_sub(half_width),
+                centre_x.saturating_add(half_width),
+                centre_y + dy,
+                ink,
+            );
+        }
+    }
+
+    /// Fill the area around (x, y) - that is, every pixel in the same
+    /// state as (x, y) which can be reached from it without going
+    /// diagonally.
+    ///
+    /// We can't allocate, so the fill remembers a limited number of places
+    /// it has still to visit. If a shape is so complex that this runs out,
+    /// as much as possible is filled and `FillIncomplete` is returned.
+    /// Calling `flood_fill` again with a point in the unfilled part will
+    /// finish the job.
+    pub fn flood_fill(&mut self, x: isize, y: isize, ink: Ink) -> Result<(), FillIncomplete> {
+        if self.locate(x, y).is_none() {
+            return Ok(());
+        }
+        let target = self.get_pixel(x, y);
+        let replacement = match ink {
+            Ink::On => true,
+            Ink::Off => false,
+            Ink::Xor => !target,
+        };
+        if replacement == target {
+            return Ok(());
+        }
+        let ink = if replacement { Ink::On } else { Ink::Off };
+        let mut stack = [(0u16, 0u16); FLOOD_STACK_LEN];
+        stack[0] = (x as u16, y as u16);
+        let mut depth = 1;
+        let mut overflowed = false;
+        while depth > 0 {
+            depth -= 1;
+            let (x, y) = (stack[depth].0 as isize, stack[depth].1 as isize);
+            if self.get_pixel(x, y) != target {
+                continue;
+            }
+            // Fill this span, as far as it goes each way
+            let mut left = x;
+            while left > 0 && self.get_pixel(left - 1, y) == target {
+                left -= 1;
+            }
+            let mut right = x;
+            while (right + 1) < self.width() as isize && self.get_pixel(right + 1, y) == target {
+                right += 1;
+            }
+            self.hline(left, right, y, ink);
+            // Remember one point from each run of target pixels touching
+            // the span, above and below
+            for &next_y in [y - 1, y + 1].iter() {
+                if next_y < 0 || next_y >= self.height as isize {
+                    continue;
+                }
+                let mut in_run = false;
+                for next_x in left..=right {
+                    if self.get_pixel(next_x, next_y) != target {
+                        in_run = false;
+                    } else if !in_run {
+                        in_run = true;
+                        if depth < FLOOD_STACK_LEN {
+                            stack[depth] = (next_x as u16, next_y as u16);
+                            depth += 1;
+                        } else {
+                            overflowed = true;
+                        }
+                    }
+                }
+            }
+        }
+        if overflowed {
+            Err(FillIncomplete)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Draw a horizontal line from `left` to `right` (inclusive).
+    fn hline(&mut self, left: isize, right: isize, y: isize, ink: Ink) {
+        if y < 0 || y >= self.height as isize {
+            return;
+        }
+        let left = left.max(0);
+        let right = right.min(self.width() as isize - 1);
+        for x in left..=right {
+            self.set_pixel(x, y, ink);
+        }
+    }
+
+    /// Draw the points on row `y` which are each of the `offsets` to the
+    /// left and right of `centre_x`, skipping any off the canvas.
+    fn circle_span(&mut self, centre_x: isize, y: isize, offsets: RangeInclusive<i128>, ink: Ink) {
+        let (first, last) = offsets.into_inner();
+        let centre_x = centre_x as i128;
+        let width = self.width() as i128;
+        // To the right, including the centre
+        for dx in first.max(-centre_x)..=last.min(width - 1 - centre_x) {
+            self.set_pixel((centre_x + dx) as isize, y, ink);
+        }
+        // To the left
+        for dx in first.max(1).max(centre_x - (width - 1))..=last.min(centre_x) {
+            self.set_pixel((centre_x - dx) as isize, y, ink);
+        }
+    }
+
+    /// Does the square around a circle touch the canvas?
+    fn overlaps(&self, centre_x: isize, centre_y: isize, radius: isize) -> bool {
+        centre_x.saturating_add(radius) >= 0
+            && centre_y.saturating_add(radius) >= 0
+            && centre_x.saturating_sub(radius) < self.width() as isize
+            && centre_y.saturating_sub(radius) < self.height as isize
+    }
+
+    /// Draw a line one step at a time along its major axis, `a`, from
+    /// `start` to `end`, working out `b` for each step like Bresenham's
+    /// algorithm (ties go towards `end`). With `swap`, `a` is y and `b` is
+    /// x. The maths is done in 128 bits, so it can't overflow whatever the
+    /// end points.
+    fn line_steps(
+        &mut self,
+        (a0, b0): (isize, isize),
+        (a1, b1): (isize, isize),
+        swap: bool,
+        ink: Ink,
+    ) {
+        let limit = if swap {
+            self.height as isize
+        } else {
+            self.width() as isize
+        };
+        // Only the steps which are on the canvas
+        let (first, last, step) = if a0 <= a1 {
+            (a0.max(0), a1.min(limit - 1), 1)
+        } else {
+            (a0.min(limit - 1), a1.max(0), -1)
+        };
+        if (step > 0 && first > last) || (step < 0 && first < last) {
+            return;
+        }
+        let da = (a1 as i128 - a0 as i128).unsigned_abs().max(1);
+        let db = (b1 as i128 - b0 as i128).unsigned_abs();
+        let b_step: i128 = if b0 <= b1 { 1 } else { -1 };
+        // How far `b` has moved is round(t * db / da), kept as a whole part
+        // and a remainder out of `2 * da` so each step is just an add.
+        // `t * db` fits, as `db <= da < 2^64`.
+        let t = (first as i128 - a0 as i128).unsigned_abs();
+        let product = t * db;
+        let (mut whole, mut remainder) = (product / da, 2 * (product % da));
+        if remainder >= da {
+            whole += 1;
+            remainder -= da;
+        } else {
+            remainder += da;
+        }
+        let mut a = first;
+        loop {
+            // Anything which doesn't fit an `isize` is off the canvas anyway
+            let b = (b0 as i128 + (b_step * whole as i128))
+                .clamp(isize::MIN as i128, isize::MAX as i128) as isize;
+            if swap {
+                self.set_pixel(b, a, ink);
+            } else {
+                self.set_pixel(a, b, ink);
+            }
+            if a == last {
+                break;
+            }
+            a += step;
+            remainder += 2 * db;
+            if remainder >= 2 * da {
+                remainder -= 2 * da;
+                whole += 1;
+            }
+        }
+    }
+
+    /// Find the byte and bit for a pixel, if it's on the canvas.
+    fn locate(&self, x: isize, y: isize) -> Option<(usize, u8)> {
+        if x < 0 || y < 0 || x >= self.width() as isize || y >= self.height as isize {
+            return None;
+        }
+        let (x, y) = (x as usize, y as usize);
+        Some((
+            (y * MODE0_USABLE_HORIZONTAL_OCTETS) + (x / 8),
+            0x80 >> (x % 8),
+        ))
+    }
+}
+
+/// The x co-ordinate the midpoint algorithm picks for a circle where
+/// `x * x + y * y` should be `squared` - the nearest to the true value.
+fn midpoint_x(squared: i128) -> i128 {
+    let root = isqrt(squared as u128) as i128;
+    if (root * root) + root < squared {
+        root + 1
+    } else {
+        root
+    }
+}
+
+/// The smallest number whose square is at least `value`, or 0.
+fn ceil_sqrt(value: i128) -> i128 {
+    if value <= 0 {
+        0
+    } else {
+        isqrt((value - 1) as u128) as i128 + 1
+    }
+}
+
+/// Integer square root, rounded down, by Newton's method.
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut root = value;
+    let mut next = root.div_ceil(2);
+    while next < root {
+        root = next;
+        next = (root + (value / root)) / 2;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEN: usize = MODE0_USABLE_HORIZONTAL_OCTETS * 16;
+
+    fn count(canvas: &Mode2Canvas) -> u32 {
+        canvas.as_slice().iter().map(|byte| byte.count_ones()).sum()
+    }
+
+    #[test]
+    fn line_clipped() {
+        let mut buffer = [0u8; LEN];
+        let mut canvas = Mode2Canvas::new(&mut buffer);
+        // The part on the canvas is the same as a line drawn from its edge
+        canvas.line(-10, -5, 20, 10, Ink::On);
+        canvas.line(0, 0, 20, 10, Ink::Xor);
+        assert_eq!(count(&canvas), 0);
+        // Both ways round give the same pixels
+        canvas.line(3, 2, 40, 15, Ink::On);
+        canvas.line(40, 15, 3, 2, Ink::Xor);
+        assert_eq!(count(&canvas), 0);
+    }
+
+    #[test]
+    fn extremes() {
+        let mut buffer = [0u8; LEN];
+        let mut canvas = Mode2Canvas::new(&mut buffer);
+        canvas.line(isize::MIN, isize::MIN, isize::MAX, isize::MAX, Ink::On);
+        assert_eq!(count(&canvas), 16);
+        assert!(canvas.get_pixel(15, 15));
+        canvas.clear(false);
+        canvas.line(isize::MAX, 5, isize::MIN, 5, Ink::On);
+        assert_eq!(count(&canvas), MODE2_WIDTH_PIXELS as u32);
+        canvas.clear(false);
+        canvas.fill_rect(isize::MIN, isize::MIN, isize::MAX, isize::MAX, Ink::On);
+        canvas.fill_rect(isize::MAX, isize::MAX, isize::MAX, isize::MAX, Ink::On);
+        canvas.rect(isize::MIN, -5, isize::MAX, isize::MAX, Ink::On);
+        assert_eq!(count(&canvas), 0);
+        canvas.fill_circle(10, 10, isize::MAX, Ink::On);
+        assert_eq!(count(&canvas), (LEN * 8) as u32);
+        canvas.fill_circle(isize::MIN, isize::MIN, isize::MAX, Ink::Off);
+        canvas.fill_circle(isize::MAX, 0, 1000, Ink::Off);
+        assert_eq!(count(&canvas), (LEN * 8) as u32);
+        canvas.clear(false);
+        canvas.circle(0, 0, isize::MAX, Ink::On);
+        canvas.circle(isize::MIN, isize::MIN, isize::MAX, Ink::On);
+        assert_eq!(count(&canvas), 0);
+        // Only the left-most point is on the canvas, on every row
+        canvas.circle(isize::MAX, 5, isize::MAX, Ink::On);
+        assert_eq!(count(&canvas), 16);
+        assert!(canvas.get_pixel(0, 15));
+    }
+
+    /// Draw a circle by walking the whole octant, as `circle` used to.
+    fn midpoint_circle(canvas: &mut Mode2Canvas, cx: isize, cy: isize, radius: isize) {
+        let (mut x, mut y) = (radius, 0);
+        let mut err = 1 - radius;
+        while x >= y {
+            for &(dx, dy) in [(x, y), (y, x)].iter() {
+                for &(sx, sy) in [(1, 1), (-1, 1), (1, -1), (-1, -1)].iter() {
+                    canvas.set_pixel(cx + (sx * dx), cy + (sy * dy), Ink::On);
+                }
+            }
+            y += 1;
+            if err < 0 {
+                err += 2 * y + 1;
+            } else {
+                x -= 1;
+                err += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    #[test]
+    fn circle() {
+        let mut expected = [0u8; LEN];
+        let mut buffer = [0u8; LEN];
+        for &(cx, cy) in [(50, 8), (0, 0), (-20, 30), (390, -10), (200, 100)].iter() {
+            for radius in 1..120 {
+                let mut reference = Mode2Canvas::new(&mut expected);
+                reference.clear(false);
+                midpoint_circle(&mut reference, cx, cy, radius);
+                let mut canvas = Mode2Canvas::new(&mut buffer);
+                canvas.clear(false);
+                // With XOR, any point drawn twice would vanish
+                canvas.circle(cx, cy, radius, Ink::Xor);
+                assert_eq!(&buffer[..], &expected[..], "{} {} {}", cx, cy, radius);
+            }
+        }
+    }
+
+    #[test]
+    fn fill_circle() {
+        let mut buffer = [0u8; LEN];
+        let mut canvas = Mode2Canvas::new(&mut buffer);
+        canvas.fill_circle(5, 5, 3, Ink::On);
+        assert_eq!(count(&canvas), 29);
+        assert!(canvas.get_pixel(5, 2) && canvas.get_pixel(8, 5));
+        assert!(!canvas.get_pixel(8, 8));
+    }
+}
+
+// End of file
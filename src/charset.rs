@@ -1,7 +1,7 @@
 /// This MS-DOS CodePage 850. It offers a compromise between the box
 /// characters of CodePage 437 and the accents of ISO 8859-1 / Latin-1.
 #[repr(u8)]
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Char {
     Null,                                   // Char 000 ' ' = REPLACEMENT CHARACTER
     SOH,                                    // Char 001 '☺' = WHITE SMILING FACE
@@ -35,6 +35,7 @@ pub enum Char {
     GS,                                     // Char 029 '↔' = LEFT RIGHT ARROW
     RS,                                     // Char 030 '▲' = BLACK UP POINTING ARROW
     US,                                     // Char 031 '▼' = BLACK DOWN POINTING ARROW
+    #[default]
     Space,                                  // Char 032 ' ' = SPACE
     ExclamationMark,                        // Char 033 '!' = EXCLAMATION MARK
     QuotationMark,                          // Char 034 '"' = QUOTATION MARK
@@ -261,9 +262,21 @@ pub enum Char {
     NoBreakSpace,                           // Char 255 '\xa0' = NO-BREAK SPACE
 }
 
-impl ::core::default::Default for Char {
-    fn default() -> Char {
-        Char::Space
+/// The error returned when converting a Unicode scalar value which has no
+/// equivalent in Code Page 850.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnmappableChar(pub char);
+
+impl ::core::convert::TryFrom<char> for Char {
+    type Error = UnmappableChar;
+
+    /// Like `Char::map_char`, but characters that can't be mapped are
+    /// reported as an error instead of being set to '?'.
+    fn try_from(ch: char) -> Result<Char, UnmappableChar> {
+        match Char::map_char(ch) {
+            Char::QuestionMark if ch != '?' => Err(UnmappableChar(ch)),
+            mapped => Ok(mapped),
+        }
     }
 }
 
@@ -272,7 +285,8 @@ impl Char {
     /// Characters that can't be mapped are set to '?' (Char::QuestionMark).
     /// Both the Unicode control characters and the relevant Unicode symbols
     /// (like BLACK HEART SUIT) are mapped into the 0..31 range, so this is
-    /// not a 1:1 mapping.
+    /// not a 1:1 mapping. Use `Char::try_from` to find out if a character
+    /// couldn't be mapped.
     pub fn map_char(ch: char) -> Char {
         match ch {
             '\u{0000}' => Char::Null,
@@ -564,6 +578,274 @@ impl Char {
         }
     }
 
+    /// Convert to the Unicode scalar value for the symbol this `Char`
+    /// displays as.
+    ///
+    /// Codes 1..31 give the symbols shown by the font (e.g. '☺' for
+    /// `Char::SOH`), not the control characters, so the result is suitable
+    /// for turning the text on screen back into a string. `Char::Null` has a
+    /// blank glyph and gives ' '.
+    pub fn to_char(self) -> char {
+        match self {
+            Char::Null => ' ',
+            Char::SOH => '☺',
+            Char::STX => '☻',
+            Char::ETX => '♥',
+            Char::EOT => '♦',
+            Char::ENQ => '♣',
+            Char::ACK => '♠',
+            Char::BEL => '•',
+            Char::BS => '◘',
+            Char::HT => '○',
+            Char::LF => '◙',
+            Char::VT => '♂',
+            Char::FF => '♀',
+            Char::CR => '♪',
+            Char::SO => '♫',
+            Char::SI => '☼',
+            Char::DLE => '►',
+            Char::DC1 => '◄',
+            Char::DC2 => '↕',
+            Char::DC3 => '‼',
+            Char::DC4 => '¶',
+            Char::NAK => '§',
+            Char::SYN => '▬',
+            Char::ETB => '↨',
+            Char::CAN => '↑',
+            Char::EM => '↓',
+            Char::SUB => '→',
+            Char::Escape => '←',
+            Char::FS => '∟',
+            Char::GS => '↔',
+            Char::RS => '▲',
+            Char::US => '▼',
+            Char::Space => ' ',
+            Char::ExclamationMark => '!',
+            Char::QuotationMark => '"',
+            Char::NumberSign => '#',
+            Char::DollarSign => '$',
+            Char::PercentSign => '%',
+            Char::Ampersand => '&',
+            Char::Apostrophe => '\'',
+            Char::LeftParenthesis => '(',
+            Char::RightParenthesis => ')',
+            Char::Asterisk => '*',
+            Char::PlusSign => '+',
+            Char::Comma => ',',
+            Char::HyphenMinus => '-',
+            Char::FullStop => '.',
+            Char::Solidus => '/',
+            Char::DigitZero => '0',
+            Char::DigitOne => '1',
+            Char::DigitTwo => '2',
+            Char::DigitThree => '3',
+            Char::DigitFour => '4',
+            Char::DigitFive => '5',
+            Char::DigitSix => '6',
+            Char::DigitSeven => '7',
+            Char::DigitEight => '8',
+            Char::DigitNine => '9',
+            Char::Colon => ':',
+            Char::Semicolon => ';',
+            Char::LessThanSign => '<',
+            Char::EqualsSign => '=',
+            Char::GreaterThanSign => '>',
+            Char::QuestionMark => '?',
+            Char::CommercialAt => '@',
+            Char::LatinCapitalLetterA => 'A',
+            Char::LatinCapitalLetterB => 'B',
+            Char::LatinCapitalLetterC => 'C',
+            Char::LatinCapitalLetterD => 'D',
+            Char::LatinCapitalLetterE => 'E',
+            Char::LatinCapitalLetterF => 'F',
+            Char::LatinCapitalLetterG => 'G',
+            Char::LatinCapitalLetterH => 'H',
+            Char::LatinCapitalLetterI => 'I',
+            Char::LatinCapitalLetterJ => 'J',
+            Char::LatinCapitalLetterK => 'K',
+            Char::LatinCapitalLetterL => 'L',
+            Char::LatinCapitalLetterM => 'M',
+            Char::LatinCapitalLetterN => 'N',
+            Char::LatinCapitalLetterO => 'O',
+            Char::LatinCapitalLetterP => 'P',
+            Char::LatinCapitalLetterQ => 'Q',
+            Char::LatinCapitalLetterR => 'R',
+            Char::LatinCapitalLetterS => 'S',
+            Char::LatinCapitalLetterT => 'T',
+            Char::LatinCapitalLetterU => 'U',
+            Char::LatinCapitalLetterV => 'V',
+            Char::LatinCapitalLetterW => 'W',
+            Char::LatinCapitalLetterX => 'X',
+            Char::LatinCapitalLetterY => 'Y',
+            Char::LatinCapitalLetterZ => 'Z',
+            Char::LeftSquareBracket => '[',
+            Char::ReverseSolidus => '\\',
+            Char::RightSquareBracket => ']',
+            Char::CircumflexAccent => '^',
+            Char::LowLine => '_',
+            Char::GraveAccent => '`',
+            Char::LatinSmallLetterA => 'a',
+            Char::LatinSmallLetterB => 'b',
+            Char::LatinSmallLetterC => 'c',
+            Char::LatinSmallLetterD => 'd',
+            Char::LatinSmallLetterE => 'e',
+            Char::LatinSmallLetterF => 'f',
+            Char::LatinSmallLetterG => 'g',
+            Char::LatinSmallLetterH => 'h',
+            Char::LatinSmallLetterI => 'i',
+            Char::LatinSmallLetterJ => 'j',
+            Char::LatinSmallLetterK => 'k',
+            Char::LatinSmallLetterL => 'l',
+            Char::LatinSmallLetterM => 'm',
+            Char::LatinSmallLetterN => 'n',
+            Char::LatinSmallLetterO => 'o',
+            Char::LatinSmallLetterP => 'p',
+            Char::LatinSmallLetterQ => 'q',
+            Char::LatinSmallLetterR => 'r',
+            Char::LatinSmallLetterS => 's',
+            Char::LatinSmallLetterT => 't',
+            Char::LatinSmallLetterU => 'u',
+            Char::LatinSmallLetterV => 'v',
+            Char::LatinSmallLetterW => 'w',
+            Char::LatinSmallLetterX => 'x',
+            Char::LatinSmallLetterY => 'y',
+            Char::LatinSmallLetterZ => 'z',
+            Char::LeftCurlyBracket => '{',
+            Char::VerticalLine => '|',
+            Char::RightCurlyBracket => '}',
+            Char::Tilde => '~',
+            Char::Delete => '\u{007f}',
+            Char::LatinCapitalLetterCWithCedilla => 'Ç',
+            Char::LatinSmallLetterUWithDiaeresis => 'ü',
+            Char::LatinSmallLetterEWithAcute => 'é',
+            Char::LatinSmallLetterAWithCircumflex => 'â',
+            Char::LatinSmallLetterAWithDiaeresis => 'ä',
+            Char::LatinSmallLetterAWithGrave => 'à',
+            Char::LatinSmallLetterAWithRingAbove => 'å',
+            Char::LatinSmallLetterCWithCedilla => 'ç',
+            Char::LatinSmallLetterEWithCircumflex => 'ê',
+            Char::LatinSmallLetterEWithDiaeresis => 'ë',
+            Char::LatinSmallLetterEWithGrave => 'è',
+            Char::LatinSmallLetterIWithDiaeresis => 'ï',
+            Char::LatinSmallLetterIWithCircumflex => 'î',
+            Char::LatinSmallLetterIWithGrave => 'ì',
+            Char::LatinCapitalLetterAWithDiaeresis => 'Ä',
+            Char::LatinCapitalLetterAWithRingAbove => 'Å',
+            Char::LatinCapitalLetterEWithAcute => 'É',
+            Char::LatinSmallLetterAe => 'æ',
+            Char::LatinCapitalLetterAe => 'Æ',
+            Char::LatinSmallLetterOWithCircumflex => 'ô',
+            Char::LatinSmallLetterOWithDiaeresis => 'ö',
+            Char::LatinSmallLetterOWithGrave => 'ò',
+            Char::LatinSmallLetterUWithCircumflex => 'û',
+            Char::LatinSmallLetterUWithGrave => 'ù',
+            Char::LatinSmallLetterYWithDiaeresis => 'ÿ',
+            Char::LatinCapitalLetterOWithDiaeresis => 'Ö',
+            Char::LatinCapitalLetterUWithDiaeresis => 'Ü',
+            Char::LatinSmallLetterOWithStroke => 'ø',
+            Char::PoundSign => '£',
+            Char::LatinCapitalLetterOWithStroke => 'Ø',
+            Char::MultiplicationSign => '×',
+            Char::LatinSmallLetterFWithHook => 'ƒ',
+            Char::LatinSmallLetterAWithAcute => 'á',
+            Char::LatinSmallLetterIWithAcute => 'í',
+            Char::LatinSmallLetterOWithAcute => 'ó',
+            Char::LatinSmallLetterUWithAcute => 'ú',
+            Char::LatinSmallLetterNWithTilde => 'ñ',
+            Char::LatinCapitalLetterNWithTilde => 'Ñ',
+            Char::FeminineOrdinalIndicator => 'ª',
+            Char::MasculineOrdinalIndicator => 'º',
+            Char::InvertedQuestionMark => '¿',
+            Char::RegisteredSign => '®',
+            Char::NotSign => '¬',
+            Char::VulgarFractionOneHalf => '½',
+            Char::VulgarFractionOneQuarter => '¼',
+            Char::InvertedExclamationMark => '¡',
+            Char::LeftPointingDoubleAngleQuotationMark => '«',
+            Char::RightPointingDoubleAngleQuotationMark => '»',
+            Char::LightShade => '░',
+            Char::MediumShade => '▒',
+            Char::DarkShade => '▓',
+            Char::BoxDrawingsLightVertical => '│',
+            Char::BoxDrawingsLightVerticalAndLeft => '┤',
+            Char::LatinCapitalLetterAWithAcute => 'Á',
+            Char::LatinCapitalLetterAWithCircumflex => 'Â',
+            Char::LatinCapitalLetterAWithGrave => 'À',
+            Char::CopyrightSign => '©',
+            Char::BoxDrawingsDoubleVerticalAndLeft => '╣',
+            Char::BoxDrawingsDoubleVertical => '║',
+            Char::BoxDrawingsDoubleDownAndLeft => '╗',
+            Char::BoxDrawingsDoubleUpAndLeft => '╝',
+            Char::CentSign => '¢',
+            Char::YenSign => '¥',
+            Char::BoxDrawingsLightDownAndLeft => '┐',
+            Char::BoxDrawingsLightUpAndRight => '└',
+            Char::BoxDrawingsLightUpAndHorizontal => '┴',
+            Char::BoxDrawingsLightDownAndHorizontal => '┬',
+            Char::BoxDrawingsLightVerticalAndRight => '├',
+            Char::BoxDrawingsLightHorizontal => '─',
+            Char::BoxDrawingsLightVerticalAndHorizontal => '┼',
+            Char::LatinSmallLetterAWithTilde => 'ã',
+            Char::LatinCapitalLetterAWithTilde => 'Ã',
+            Char::BoxDrawingsDoubleUpAndRight => '╚',
+            Char::BoxDrawingsDoubleDownAndRight => '╔',
+            Char::BoxDrawingsDoubleUpAndHorizontal => '╩',
+            Char::BoxDrawingsDoubleDownAndHorizontal => '╦',
+            Char::BoxDrawingsDoubleVerticalAndRight => '╠',
+            Char::BoxDrawingsDoubleHorizontal => '═',
+            Char::BoxDrawingsDoubleVerticalAndHorizontal => '╬',
+            Char::CurrencySign => '¤',
+            Char::LatinSmallLetterEth => 'ð',
+            Char::LatinCapitalLetterEth => 'Ð',
+            Char::LatinCapitalLetterEWithCircumflex => 'Ê',
+            Char::LatinCapitalLetterEWithDiaeresis => 'Ë',
+            Char::LatinCapitalLetterEWithGrave => 'È',
+            Char::LatinSmallLetterDotlessI => 'ı',
+            Char::LatinCapitalLetterIWithAcute => 'Í',
+            Char::LatinCapitalLetterIWithCircumflex => 'Î',
+            Char::LatinCapitalLetterIWithDiaeresis => 'Ï',
+            Char::BoxDrawingsLightUpAndLeft => '┘',
+            Char::BoxDrawingsLightDownAndRight => '┌',
+            Char::FullBlock => '█',
+            Char::LowerHalfBlock => '▄',
+            Char::BrokenBar => '¦',
+            Char::LatinCapitalLetterIWithGrave => 'Ì',
+            Char::UpperHalfBlock => '▀',
+            Char::LatinCapitalLetterOWithAcute => 'Ó',
+            Char::LatinSmallLetterSharpS => 'ß',
+            Char::LatinCapitalLetterOWithCircumflex => 'Ô',
+            Char::LatinCapitalLetterOWithGrave => 'Ò',
+            Char::LatinSmallLetterOWithTilde => 'õ',
+            Char::LatinCapitalLetterOWithTilde => 'Õ',
+            Char::MicroSign => 'µ',
+            Char::LatinSmallLetterThorn => 'þ',
+            Char::LatinCapitalLetterThorn => 'Þ',
+            Char::LatinCapitalLetterUWithAcute => 'Ú',
+            Char::LatinCapitalLetterUWithCircumflex => 'Û',
+            Char::LatinCapitalLetterUWithGrave => 'Ù',
+            Char::LatinSmallLetterYWithAcute => 'ý',
+            Char::LatinCapitalLetterYWithAcute => 'Ý',
+            Char::Macron => '¯',
+            Char::AcuteAccent => '´',
+            Char::SoftHyphen => '\u{00ad}',
+            Char::PlusMinusSign => '±',
+            Char::DoubleLowLine => '‗',
+            Char::VulgarFractionThreeQuarters => '¾',
+            Char::PilcrowSign => '¶',
+            Char::SectionSign => '§',
+            Char::DivisionSign => '÷',
+            Char::Cedilla => '¸',
+            Char::DegreeSign => '°',
+            Char::Diaeresis => '¨',
+            Char::MiddleDot => '·',
+            Char::SuperscriptOne => '¹',
+            Char::SuperscriptThree => '³',
+            Char::SuperscriptTwo => '²',
+            Char::BlackSquare => '■',
+            Char::NoBreakSpace => '\u{00a0}',
+        }
+    }
+
     /// Convert an 8-bit integer into a `Char` (assumes input is in CodePage
     /// 850).
     pub fn from_byte(byte: u8) -> Char {
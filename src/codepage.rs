@@ -0,0 +1,214 @@
+//! Support for code pages other than Code Page 850.
+//!
+//! The text buffer always holds 8-bit `Char` values, and the font decides
+//! what each one looks like. A `CodePage` has the table used to turn Unicode
+//! into those 8-bit values, so that text written with `core::fmt::Write`
+//! comes out correctly with the matching font. Each font is in its own
+//! module (e.g. `koi8_r::FONT_DATA`), and is only linked in if you use it.
+//!
+//! Every bundled font has the same glyphs as Code Page 850 for 0x00..0x7F,
+//! so only the top half of each code page needs a table.
+
+use super::{cp437, freebsd_cp850, iso_8859_1, iso_8859_15, koi8_r};
+use charset::{Char, UnmappableChar};
+
+/// The code pages we have fonts for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum CodePage {
+    /// MS-DOS Code Page 850 (the default). See `Char`.
+    #[default]
+    Cp850,
+    /// MS-DOS Code Page 437, the original IBM PC character set, with the
+    /// full set of box drawing characters.
+    Cp437,
+    /// ISO 8859-1 (Latin-1).
+    Iso8859_1,
+    /// ISO 8859-15 (Latin-9) - Latin-1 with the Euro sign.
+    Iso8859_15,
+    /// KOI8-R, for Russian and Bulgarian Cyrillic.
+    Koi8R,
+}
+
+impl CodePage {
+    /// Convert a Unicode scalar value into an 8-bit character in this code
+    /// page. Characters that can't be mapped are set to '?'.
+    pub fn map_char(self, ch: char) -> Char {
+        self.try_map_char(ch).unwrap_or(Char::QuestionMark)
+    }
+
+    /// Convert a Unicode scalar value into an 8-bit character in this code
+    /// page, reporting characters that can't be mapped.
+    pub fn try_map_char(self, ch: char) -> Result<Char, UnmappableChar> {
+        let table = match self.high_table() {
+            Some(table) => table,
+            None => return ::core::convert::TryFrom::try_from(ch),
+        };
+        if (ch as u32) < 0x80 {
+            return Ok(Char::from_byte(ch as u8));
+        }
+        if let Some(idx) = table.iter().position(|&x| x == ch) {
+            return Ok(Char::from_byte(0x80 + idx as u8));
+        }
+        // The symbols shown for the control characters are the same in every
+        // font.
+        (1..0x20)
+            .map(Char::from_byte)
+            .find(|glyph| glyph.to_char() == ch)
+            .ok_or(UnmappableChar(ch))
+    }
+
+    /// Convert an 8-bit character in this code page into the Unicode scalar
+    /// value for the symbol it displays as. See `Char::to_char`.
+    pub fn to_char(self, glyph: Char) -> char {
+        let byte = glyph.to_byte();
+        match self.high_table() {
+            Some(table) if byte >= 0x80 => table[(byte - 0x80) as usize],
+            _ => glyph.to_char(),
+        }
+    }
+
+    /// Get the bundled font for this code page.
+    ///
+    /// This links in every bundled font (about 4 KiB each), as the code page
+    /// isn't known until run-time. If you know which font you want, use its
+    /// `FONT_DATA` directly instead.
+    pub fn font(self) -> &'static [u8] {
+        match self {
+            CodePage::Cp850 => &freebsd_cp850::FONT_DATA,
+            CodePage::Cp437 => &cp437::FONT_DATA,
+            CodePage::Iso8859_1 => &iso_8859_1::FONT_DATA,
+            CodePage::Iso8859_15 => &iso_8859_15::FONT_DATA,
+            CodePage::Koi8R => &koi8_r::FONT_DATA,
+        }
+    }
+
+    /// Get the Unicode values for 0x80..0xFF. Code Page 850 uses the `Char`
+    /// enum instead.
+    fn high_table(self) -> Option<&'static [char; 128]> {
+        match self {
+            CodePage::Cp850 => None,
+            CodePage::Cp437 => Some(&CP437_HIGH),
+            CodePage::Iso8859_1 => Some(&ISO_8859_1_HIGH),
+            CodePage::Iso8859_15 => Some(&ISO_8859_15_HIGH),
+            CodePage::Koi8R => Some(&KOI8_R_HIGH),
+        }
+    }
+}
+
+/// Code Page 437, 0x80..0xFF
+static CP437_HIGH: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', 'É', 'æ', 'Æ',
+    'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', 'á', 'í', 'ó', 'ú', 'ñ', 'Ñ',
+    'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕',
+    '╣', '║', '╗', '╝', '╜', '╛', '┐', '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦',
+    '╠', '═', '╬', '╧', '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐',
+    '▀', 'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', '≡', '±',
+    '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{00a0}',
+];
+
+/// ISO 8859-1, 0x80..0xFF. 0x80..0x9F are the C1 control codes.
+static ISO_8859_1_HIGH: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}',
+    '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}',
+    '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '\u{00ad}', '®', '¯',
+    '°', '±', '²', '³', '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â',
+    'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ',
+    'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è',
+    'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û',
+    'ü', 'ý', 'þ', 'ÿ',
+];
+
+/// ISO 8859-15, 0x80..0xFF. 0x80..0x9F are the C1 control codes.
+static ISO_8859_15_HIGH: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}',
+    '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}',
+    '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '¡', '¢', '£', '€', '¥', 'Š', '§', 'š', '©', 'ª', '«', '¬', '\u{00ad}', '®', '¯',
+    '°', '±', '²', '³', 'Ž', 'µ', '¶', '·', 'ž', '¹', 'º', '»', 'Œ', 'œ', 'Ÿ', '¿', 'À', 'Á', 'Â',
+    'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ',
+    'Ö', '×', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è',
+    'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û',
+    'ü', 'ý', 'þ', 'ÿ',
+];
+
+/// KOI8-R, 0x80..0xFF
+static KOI8_R_HIGH: [char; 128] = [
+    '─', '│', '┌', '┐', '└', '┘', '├', '┤', '┬', '┴', '┼', '▀', '▄', '█', '▌', '▐', '░', '▒', '▓',
+    '⌠', '■', '∙', '√', '≈', '≤', '≥', '\u{00a0}', '⌡', '°', '²', '·', '÷', '═', '║', '╒', 'ё',
+    '╓', '╔', '╕', '╖', '╗', '╘', '╙', '╚', '╛', '╜', '╝', '╞', '╟', '╠', '╡', 'Ё', '╢', '╣', '╤',
+    '╥', '╦', '╧', '╨', '╩', '╪', '╫', '╬', '©', 'ю', 'а', 'б', 'ц', 'д', 'е', 'ф', 'г', 'х', 'и',
+    'й', 'к', 'л', 'м', 'н', 'о', 'п', 'я', 'р', 'с', 'т', 'у', 'ж', 'в', 'ь', 'ы', 'з', 'ш', 'э',
+    'щ', 'ч', 'ъ', 'Ю', 'А', 'Б', 'Ц', 'Д', 'Е', 'Ф', 'Г', 'Х', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О',
+    'П', 'Я', 'Р', 'С', 'Т', 'У', 'Ж', 'В', 'Ь', 'Ы', 'З', 'Ш', 'Э', 'Щ', 'Ч', 'Ъ',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [CodePage; 5] = [
+        CodePage::Cp850,
+        CodePage::Cp437,
+        CodePage::Iso8859_1,
+        CodePage::Iso8859_15,
+        CodePage::Koi8R,
+    ];
+
+    #[test]
+    fn round_trip() {
+        for &code_page in ALL.iter() {
+            for byte in 1..=0xFF {
+                let ch = code_page.to_char(Char::from_byte(byte));
+                // Some symbols (like '¶' in Code Page 850) are shown for a
+                // control character as well, so only the text must match
+                let glyph = code_page.try_map_char(ch).unwrap();
+                assert_eq!(
+                    code_page.to_char(glyph),
+                    ch,
+                    "{:?} 0x{:02x}",
+                    code_page,
+                    byte
+                );
+                if byte >= 0x20 {
+                    assert_eq!(glyph.to_byte(), byte, "{:?} {:?}", code_page, ch);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn high_half() {
+        let cases = [
+            (CodePage::Cp850, 'Ø', 0x9D),
+            (CodePage::Cp437, 'α', 0xE0),
+            (CodePage::Cp437, '╒', 0xD5),
+            (CodePage::Iso8859_1, '¤', 0xA4),
+            (CodePage::Iso8859_15, '€', 0xA4),
+            (CodePage::Koi8R, 'Ж', 0xF6),
+            (CodePage::Koi8R, 'ж', 0xD6),
+        ];
+        for &(code_page, ch, byte) in cases.iter() {
+            assert_eq!(code_page.map_char(ch).to_byte(), byte);
+            assert_eq!(code_page.to_char(Char::from_byte(byte)), ch);
+        }
+    }
+
+    #[test]
+    fn unmappable() {
+        assert_eq!(CodePage::Koi8R.try_map_char('é'), Err(UnmappableChar('é')));
+        assert_eq!(CodePage::Iso8859_15.map_char('¤'), Char::QuestionMark);
+        assert_eq!(CodePage::Cp437.map_char('\u{1F600}'), Char::QuestionMark);
+    }
+
+    #[test]
+    fn fonts() {
+        for &code_page in ALL.iter() {
+            assert_eq!(code_page.font().len(), 256 * 16);
+        }
+    }
+}
+
+// End of file
@@ -0,0 +1,239 @@
+//! Screen effects, built on `FrameBuffer::map_line`.
+//!
+//! Every visible scan-line can show any rendered scan-line, so by changing
+//! the mapping we can distort the picture without touching the text buffer.
+//! Each function here rewrites the mapping for a band of visible lines and
+//! leaves the rest of the screen alone, so effects can be applied to
+//! different parts of the screen at once (for example, a frozen status bar
+//! above a wobbling picture). Any part of a band below the bottom of the
+//! screen is ignored, so the effect fits the part which is visible.
+//!
+//! To animate an effect, call it once per frame with a new `frame` or
+//! `progress` value. Do this in the vertical blanking interval (i.e. when
+//! `FrameBuffer::line` returns `None`) to avoid tearing.
+
+use core::ops::Range;
+
+use super::{FrameBuffer, Hardware, MODE0_USABLE_LINES};
+
+/// One cycle of a sine wave, scaled to +/- 127.
+static SINE: [i8; 64] = [
+    0, 12, 25, 37, 49, 60, 71, 81, 90, 98, 106, 112, 117, 122, 125, 126, 127, 126, 125, 122, 117,
+    112, 106, 98, 90, 81, 71, 60, 49, 37, 25, 12, 0, -12, -25, -37, -49, -60, -71, -81, -90, -98,
+    -106, -112, -117, -122, -125, -126, -127, -126, -125, -122, -117, -112, -106, -98, -90, -81,
+    -71, -60, -49, -37, -25, -12,
+];
+
+/// Put every visible line back to showing itself.
+pub fn reset<T>(fb: &mut FrameBuffer<T>)
+where
+    T: Hardware,
+{
+    apply(fb, 0..MODE0_USABLE_LINES as u16, |line| line as isize);
+}
+
+/// Turn the given band of lines upside down.
+pub fn flip<T>(fb: &mut FrameBuffer<T>, band: Range<u16>)
+where
+    T: Hardware,
+{
+    let band = visible(band);
+    let total = band.start as isize + band.end as isize - 1;
+    apply(fb, band, |line| total - line as isize);
+}
+
+/// Stretch the middle of the given band vertically to fill the band.
+/// `percent` is the magnification - 100 shows the band as normal, 200 shows
+/// the middle half at twice the height, and values below 100 squash the
+/// band (repeating the lines at its edges).
+pub fn zoom<T>(fb: &mut FrameBuffer<T>, band: Range<u16>, percent: u16)
+where
+    T: Hardware,
+{
+    let band = visible(band);
+    let percent = percent.max(1) as isize;
+    let (start, end) = (band.start as isize, band.end as isize);
+    let centre = (start + end) / 2;
+    apply(fb, band, |line| {
+        let source = centre + ((line as isize - centre) * 100) / percent;
+        source.max(start).min(end - 1)
+    });
+}
+
+/// Ripple the given band up and down like a flag. `amplitude` is how many
+/// lines each line can move by and `wavelength` is the height of one ripple,
+/// in lines. Increase `frame` by one each frame (e.g. pass
+/// `FrameBuffer::frame`) to make the ripples move.
+pub fn wobble<T>(
+    fb: &mut FrameBuffer<T>,
+    band: Range<u16>,
+    frame: usize,
+    amplitude: u16,
+    wavelength: u16,
+) where
+    T: Hardware,
+{
+    let band = visible(band);
+    let wavelength = wavelength.max(1) as usize;
+    let (start, end) = (band.start as isize, band.end as isize);
+    apply(fb, band, |line| {
+        // `frame` counts up forever, so reduce it first to avoid overflow
+        let phase = ((line as usize * SINE.len()) / wavelength + frame % SINE.len()) % SINE.len();
+        let source = line as isize + (amplitude as isize * SINE[phase] as isize) / 127;
+        source.max(start).min(end - 1)
+    });
+}
+
+/// Close a pair of curtains over the given band, from its top and bottom
+/// edges. `progress` lines at each edge show `fill_line` (pick a line which
+/// is blank, or a solid colour), so the curtains have met once `progress`
+/// reaches half the band's height.
+///
+/// To wipe from one screen to another, step `progress` up until the band is
+/// covered, draw the new screen, then step it back down to 0.
+pub fn curtain<T>(fb: &mut FrameBuffer<T>, band: Range<u16>, progress: u16, fill_line: u16)
+where
+    T: Hardware,
+{
+    let band = visible(band);
+    let (start, end) = (band.start, band.end);
+    apply(fb, band, |line| {
+        if line < start.saturating_add(progress) || line >= end.saturating_sub(progress) {
+            fill_line as isize
+        } else {
+            line as isize
+        }
+    });
+}
+
+/// Split the screen at `split_line`. The lines above stay frozen, while the
+/// lines below are scrolled up by `offset` lines, wrapping around within
+/// the lower part. Step `offset` each frame for a smooth scroll under a
+/// fixed header.
+pub fn split_screen<T>(fb: &mut FrameBuffer<T>, split_line: u16, offset: u16)
+where
+    T: Hardware,
+{
+    let split_line = split_line.min(MODE0_USABLE_LINES as u16);
+    let height = (MODE0_USABLE_LINES as u16 - split_line) as isize;
+    apply(fb, 0..split_line, |line| line as isize);
+    if height > 0 {
+        apply(fb, split_line..MODE0_USABLE_LINES as u16, |line| {
+            split_line as isize + (line as isize - split_line as isize + offset as isize) % height
+        });
+    }
+}
+
+/// The part of `band` which is on the screen.
+fn visible(band: Range<u16>) -> Range<u16> {
+    let end = band.end.min(MODE0_USABLE_LINES as u16);
+    band.start.min(end)..end
+}
+
+/// Map each visible line in the band to the rendered line given by `source`.
+/// Lines outside the screen are ignored.
+fn apply<T, F>(fb: &mut FrameBuffer<T>, band: Range<u16>, source: F)
+where
+    T: Hardware,
+    F: Fn(u16) -> isize,
+{
+    for line in band {
+        let rendered = source(line);
+        if rendered >= 0 && (rendered as usize) < MODE0_USABLE_LINES {
+            fb.map_line(line, rendered as u16);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ModeInfo, XRGBColour};
+    use super::*;
+    use std::boxed::Box;
+
+    struct NullHardware;
+
+    impl Hardware for NullHardware {
+        fn configure(&mut self, _mode_info: &ModeInfo) {}
+        fn vsync_on(&mut self) {}
+        fn vsync_off(&mut self) {}
+        fn write_pixels(&mut self, _xrgb: XRGBColour) {}
+    }
+
+    fn frame_buffer() -> Box<FrameBuffer<NullHardware>> {
+        let mut fb = Box::new(FrameBuffer::new());
+        fb.init(NullHardware);
+        fb
+    }
+
+    /// Check every visible line maps to itself, except those in `band`.
+    fn unchanged_outside(fb: &FrameBuffer<NullHardware>, band: Range<usize>) {
+        for (line, &rendered) in fb.roller_buffer.iter().enumerate() {
+            if !band.contains(&line) {
+                assert_eq!(rendered as usize, line);
+            }
+        }
+    }
+
+    #[test]
+    fn flip() {
+        let mut fb = frame_buffer();
+        super::flip(&mut fb, 10..20);
+        assert_eq!(fb.roller_buffer[10], 19);
+        assert_eq!(fb.roller_buffer[19], 10);
+        assert_eq!(fb.roller_buffer[14], 15);
+        unchanged_outside(&fb, 10..20);
+        super::reset(&mut fb);
+        unchanged_outside(&fb, 0..0);
+    }
+
+    #[test]
+    fn bands_are_clipped() {
+        let last = MODE0_USABLE_LINES as u16;
+        let mut fb = frame_buffer();
+        // Only the part on the screen is flipped
+        super::flip(&mut fb, last - 4..u16::MAX);
+        assert_eq!(fb.roller_buffer[last as usize - 4], last - 1);
+        assert_eq!(fb.roller_buffer[last as usize - 1], last - 4);
+        // Bands which are empty, backwards or off the screen do nothing
+        let mut fb = frame_buffer();
+        super::flip(&mut fb, u16::MAX - 1..u16::MAX);
+        super::flip(&mut fb, Range { start: 20, end: 10 });
+        super::zoom(&mut fb, last..u16::MAX, 200);
+        super::wobble(&mut fb, u16::MAX..u16::MAX, 0, 10, 10);
+        super::curtain(&mut fb, last + 10..last + 20, 5, 0);
+        unchanged_outside(&fb, 0..0);
+    }
+
+    #[test]
+    fn wobble() {
+        let mut fb = frame_buffer();
+        super::wobble(&mut fb, 100..200, 0, 10, 64);
+        unchanged_outside(&fb, 100..200);
+        // Each line moves by the sine wave, but stays in the band
+        for line in 100..200 {
+            let rendered = fb.roller_buffer[line] as isize;
+            assert!((rendered - line as isize).abs() <= 10);
+            assert!((100..200).contains(&rendered));
+        }
+        // A quarter wave in, the line is shown from 10 further down
+        assert_eq!(fb.roller_buffer[144], 154);
+        // The frame counter can be anything
+        super::wobble(&mut fb, 100..200, usize::MAX, 10, 64);
+        let mut fb2 = frame_buffer();
+        super::wobble(&mut fb2, 100..200, usize::MAX % 64, 10, 64);
+        assert_eq!(&fb.roller_buffer[..], &fb2.roller_buffer[..]);
+    }
+
+    #[test]
+    fn curtain() {
+        let mut fb = frame_buffer();
+        super::curtain(&mut fb, 10..20, 3, 0);
+        assert_eq!(
+            &fb.roller_buffer[9..21],
+            &[9, 0, 0, 0, 13, 14, 15, 16, 0, 0, 0, 20]
+        );
+    }
+}
+
+// End of file
@@ -0,0 +1,4390 @@
+/// The font data here must be in the same order as the `Char` enum. This
+/// was generated by `font-tool` from `src/freebsd_cp850.rs`.
+/// Each glyph has been made bold, by ORing each row with itself shifted
+/// one pixel to the right.
+///
+/// The font data here must be in the same order as the `Char` enum. This is
+/// the cp850-8x16 font from FreeBSD. See
+/// http://web.mit.edu/freebsd/head/share/syscons/fonts/cp850-8x16.fnt
+///
+/// The compilation of software known as FreeBSD is distributed under the
+/// following terms:
+///
+/// Copyright (c) 1992-2014 The FreeBSD Project. All rights reserved.
+///
+/// Redistribution and use in source and binary forms, with or without
+/// modification, are permitted provided that the following conditions
+/// are met:
+/// 1. Redistributions of source code must retain the above copyright
+///    notice, this list of conditions and the following disclaimer.
+/// 2. Redistributions in binary form must reproduce the above copyright
+///    notice, this list of conditions and the following disclaimer in the
+///    documentation and/or other materials provided with the distribution.
+///
+/// THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+/// ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+/// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+/// ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+/// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+/// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+/// OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+/// HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+/// LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+/// OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+/// SUCH DAMAGE.
+pub static FONT_DATA: [u8; 256 * super::MAX_FONT_HEIGHT] = [
+    // Char::Null
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SOH
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1100_0001,
+    0b1111_0111,
+    0b1100_0001,
+    0b1100_0001,
+    0b1111_1111,
+    0b1101_1101,
+    0b1100_0001,
+    0b1100_0001,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::STX
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0011,
+    0b1111_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ETX
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::EOT
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1111_1111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ENQ
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0011_1110,
+    0b1111_0111,
+    0b1111_0111,
+    0b1111_0111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ACK
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BEL
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0011_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BS
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_0111,
+    0b1110_0011,
+    0b1110_0011,
+    0b1111_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // Char::HT
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b0110_0011,
+    0b0110_0011,
+    0b0111_0111,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LF
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0011,
+    0b1101_1101,
+    0b1111_1111,
+    0b1111_1111,
+    0b1101_1101,
+    0b1110_0011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // Char::VT
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1111,
+    0b0000_1111,
+    0b0001_1111,
+    0b0011_1011,
+    0b0111_1100,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::FF
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CR
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_1011,
+    0b0011_1111,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1000,
+    0b1111_1000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SO
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0111_0011,
+    0b0111_1111,
+    0b0111_0011,
+    0b0111_0011,
+    0b0111_0011,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_0111,
+    0b1111_0111,
+    0b1110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SI
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1111,
+    0b0011_1110,
+    0b1111_0111,
+    0b0011_1110,
+    0b1111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DLE
+    0b0000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b1111_0000,
+    0b1111_1000,
+    0b1111_1100,
+    0b1111_1111,
+    0b1111_1100,
+    0b1111_1000,
+    0b1111_0000,
+    0b1110_0000,
+    0b1100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DC1
+    0b0000_0000,
+    0b0000_0011,
+    0b0000_0111,
+    0b0000_1111,
+    0b0001_1111,
+    0b0011_1111,
+    0b1111_1111,
+    0b0011_1111,
+    0b0001_1111,
+    0b0000_1111,
+    0b0000_0111,
+    0b0000_0011,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DC2
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DC3
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DC4
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::NAK
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b0111_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_1110,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SYN
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ETB
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CAN
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::EM
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SUB
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_1110,
+    0b1111_1111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Escape
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0111_0000,
+    0b1111_1111,
+    0b0111_0000,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::FS
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::GS
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1111_1111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RS
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_1110,
+    0b1111_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::US
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Space
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ExclamationMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0011_1110,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::QuotationMark
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::NumberSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0111_1110,
+    0b1111_1111,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b1111_1111,
+    0b0111_1110,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DollarSign
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0011,
+    0b1110_0000,
+    0b0111_1110,
+    0b0000_0111,
+    0b0000_0111,
+    0b1100_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::PercentSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0011,
+    0b1110_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0111,
+    0b1100_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Ampersand
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1100,
+    0b0111_1111,
+    0b1111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Apostrophe
+    0b0000_0000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LeftParenthesis
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RightParenthesis
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Asterisk
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0011_1110,
+    0b1111_1111,
+    0b0011_1110,
+    0b0111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::PlusSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Comma
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::HyphenMinus
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::FullStop
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Solidus
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0011,
+    0b0000_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0000,
+    0b1100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitZero
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitOne
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1100,
+    0b0111_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitTwo
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitThree
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0011_1110,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitFour
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1110,
+    0b0011_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1111_1111,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitFive
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1111_1110,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitSix
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitSeven
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1110_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitEight
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DigitNine
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_1110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Colon
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Semicolon
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LessThanSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::EqualsSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::GreaterThanSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::QuestionMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CommercialAt
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1110,
+    0b1110_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterA
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterB
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterC
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b1110_0011,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0011,
+    0b0111_0111,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterD
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterE
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0000,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterF
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterG
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b1110_0011,
+    0b1110_0000,
+    0b1110_0000,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_0111,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterH
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterI
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterJ
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1111,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterK
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b1111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterL
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterM
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterN
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1111_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterO
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterP
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterQ
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0000_1110,
+    0b0000_1111,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterR
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b1111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterS
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_0000,
+    0b0011_1100,
+    0b0000_1110,
+    0b0000_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterT
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterU
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterV
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterW
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterX
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1100,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterY
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterZ
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1110_0111,
+    0b1100_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0011,
+    0b1110_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LeftSquareBracket
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::ReverseSolidus
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b1111_0000,
+    0b0111_1000,
+    0b0011_1100,
+    0b0001_1110,
+    0b0000_1111,
+    0b0000_0111,
+    0b0000_0011,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RightSquareBracket
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CircumflexAccent
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LowLine
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::GraveAccent
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterA
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterB
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_1100,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterC
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterD
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0011_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterE
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterF
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0011_1111,
+    0b0011_1011,
+    0b0011_1000,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterG
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1110,
+    0b0000_1110,
+    0b1110_1110,
+    0b0111_1100,
+    0b0000_0000,
+    // Char::LatinSmallLetterH
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_1110,
+    0b0111_1111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b1111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterI
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterJ
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0000,
+    0b0000_1111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0000_0000,
+    // Char::LatinSmallLetterK
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1110,
+    0b0111_0111,
+    0b1111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterL
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterM
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterN
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterO
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterP
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    // Char::LatinSmallLetterQ
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1110,
+    0b0000_1110,
+    0b0000_1110,
+    0b0001_1111,
+    0b0000_0000,
+    // Char::LatinSmallLetterR
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_1111,
+    0b0111_0111,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterS
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b0111_0000,
+    0b0011_1100,
+    0b0000_1110,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterT
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b1111_1110,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1111,
+    0b0001_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterU
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterV
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterW
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterX
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0011_1100,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterY
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1111,
+    0b0000_0111,
+    0b0000_1110,
+    0b1111_1100,
+    0b0000_0000,
+    // Char::LatinSmallLetterZ
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1110_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LeftCurlyBracket
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::VerticalLine
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RightCurlyBracket
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Tilde
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Delete
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterCWithCedilla
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b1110_0011,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0011,
+    0b0111_0111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterUWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterEWithAcute
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAWithCircumflex
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAWithGrave
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAWithRingAbove
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterCWithCedilla
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0001_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterEWithCircumflex
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterEWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterEWithGrave
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterIWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterIWithCircumflex
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1110,
+    0b0111_0111,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterIWithGrave
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAWithDiaeresis
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAWithRingAbove
+    0b0011_1100,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterEWithAcute
+    0b0000_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAe
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0011_1111,
+    0b0011_1111,
+    0b0111_1111,
+    0b1111_1100,
+    0b1111_1100,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAe
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1111_1111,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithCircumflex
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithGrave
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterUWithCircumflex
+    0b0000_0000,
+    0b0011_1000,
+    0b0111_1100,
+    0b1110_1110,
+    0b0000_0000,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterUWithGrave
+    0b0000_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterYWithDiaeresis
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1111,
+    0b0000_0111,
+    0b0000_1110,
+    0b0111_1100,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithDiaeresis
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterUWithDiaeresis
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithStroke
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::PoundSign
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_0110,
+    0b0111_0000,
+    0b1111_1000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_0111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithStroke
+    0b0000_0000,
+    0b0000_0110,
+    0b0111_1110,
+    0b1110_1111,
+    0b1110_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_0111,
+    0b1111_0111,
+    0b0111_1110,
+    0b0110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::MultiplicationSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterFWithHook
+    0b0000_0000,
+    0b0000_1111,
+    0b0001_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterAWithAcute
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterIWithAcute
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithAcute
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterUWithAcute
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterNWithTilde
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b1111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterNWithTilde
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b1110_0111,
+    0b1111_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::FeminineOrdinalIndicator
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1111,
+    0b0000_0000,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::MasculineOrdinalIndicator
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::InvertedQuestionMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RegisteredSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1100_0011,
+    0b1111_1011,
+    0b1111_1111,
+    0b1111_1011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1100_0011,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::NotSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::VulgarFractionOneHalf
+    0b0000_0000,
+    0b0111_0000,
+    0b1111_0000,
+    0b0111_0011,
+    0b0111_0111,
+    0b0111_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0000,
+    0b1111_1110,
+    0b1100_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::VulgarFractionOneQuarter
+    0b0000_0000,
+    0b0111_0000,
+    0b1111_0000,
+    0b0111_0011,
+    0b0111_0111,
+    0b0111_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0111,
+    0b1110_1111,
+    0b1101_1111,
+    0b0011_1111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::InvertedExclamationMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0011_1110,
+    0b0011_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LeftPointingDoubleAngleQuotationMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0111_1110,
+    0b1111_1100,
+    0b0111_1110,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::RightPointingDoubleAngleQuotationMark
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0111_1110,
+    0b0011_1111,
+    0b0111_1110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LightShade
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    0b0001_1001,
+    0b0110_0110,
+    // Char::MediumShade
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    // Char::DarkShade
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    // Char::BoxDrawingsLightVertical
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::BoxDrawingsLightVerticalAndLeft
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::LatinCapitalLetterAWithAcute
+    0b0111_0000,
+    0b1110_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAWithCircumflex
+    0b0111_1110,
+    0b1110_0111,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAWithGrave
+    0b0000_1110,
+    0b0000_0111,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CopyrightSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1100_0011,
+    0b1101_1111,
+    0b1111_0011,
+    0b1111_0011,
+    0b1111_0011,
+    0b1101_1111,
+    0b1100_0011,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsDoubleVerticalAndLeft
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b1111_1111,
+    0b0000_0111,
+    0b1111_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleVertical
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleDownAndLeft
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0111,
+    0b1111_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleUpAndLeft
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b1111_1111,
+    0b0000_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::CentSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0000,
+    0b1110_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::YenSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightDownAndLeft
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::BoxDrawingsLightUpAndRight
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightUpAndHorizontal
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightDownAndHorizontal
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::BoxDrawingsLightVerticalAndRight
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::BoxDrawingsLightHorizontal
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightVerticalAndHorizontal
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::LatinSmallLetterAWithTilde
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_1110,
+    0b0111_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterAWithTilde
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsDoubleUpAndRight
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1000,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsDoubleDownAndRight
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_1000,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleUpAndHorizontal
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsDoubleDownAndHorizontal
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleVerticalAndRight
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1000,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::BoxDrawingsDoubleHorizontal
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsDoubleVerticalAndHorizontal
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    0b0011_1111,
+    // Char::CurrencySign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0111,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterEth
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0111,
+    0b0011_1111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterEth
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterEWithCircumflex
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterEWithDiaeresis
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterEWithGrave
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b1111_1111,
+    0b0111_0111,
+    0b0111_0011,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_1100,
+    0b0111_0011,
+    0b0111_0111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterDotlessI
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterIWithAcute
+    0b0000_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterIWithCircumflex
+    0b0011_1110,
+    0b0111_0111,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterIWithDiaeresis
+    0b0000_0000,
+    0b0111_0111,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightUpAndLeft
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BoxDrawingsLightDownAndRight
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    // Char::FullBlock
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // Char::LowerHalfBlock
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // Char::BrokenBar
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterIWithGrave
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::UpperHalfBlock
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithAcute
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterSharpS
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1110_1110,
+    0b1110_1110,
+    0b1110_1110,
+    0b1111_1100,
+    0b1110_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithCircumflex
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithGrave
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterOWithTilde
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterOWithTilde
+    0b0111_1111,
+    0b1111_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::MicroSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0000,
+    0b0111_0000,
+    0b1110_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterThorn
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0111_0000,
+    0b0111_0000,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0000,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterThorn
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0111_0000,
+    0b0111_1110,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_1110,
+    0b0111_0000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterUWithAcute
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterUWithCircumflex
+    0b0011_1100,
+    0b0111_1110,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinCapitalLetterUWithGrave
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::LatinSmallLetterYWithAcute
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1111,
+    0b0000_0111,
+    0b0000_1110,
+    0b1111_1100,
+    0b0000_0000,
+    // Char::LatinCapitalLetterYWithAcute
+    0b0000_1110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0111_0111,
+    0b0011_1110,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Macron
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::AcuteAccent
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SoftHyphen
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::PlusMinusSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0001_1100,
+    0b0111_1111,
+    0b0001_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DoubleLowLine
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    // Char::VulgarFractionThreeQuarters
+    0b0000_0000,
+    0b1111_0000,
+    0b0011_1000,
+    0b0111_0011,
+    0b0011_1111,
+    0b1111_1110,
+    0b0001_1100,
+    0b0011_1000,
+    0b0111_0111,
+    0b1110_1111,
+    0b1101_1111,
+    0b0011_1111,
+    0b0000_0111,
+    0b0000_0111,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::PilcrowSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SectionSign
+    0b0000_0000,
+    0b0111_1110,
+    0b1110_0111,
+    0b0111_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1110_0111,
+    0b1110_0111,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_1110,
+    0b1110_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DivisionSign
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0111_1111,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Cedilla
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::DegreeSign
+    0b0000_0000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0111_1110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::Diaeresis
+    0b0000_0000,
+    0b1110_0111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::MiddleDot
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SuperscriptOne
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0001_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SuperscriptThree
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0111,
+    0b0011_1110,
+    0b0000_0111,
+    0b0000_0111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::SuperscriptTwo
+    0b0000_0000,
+    0b0011_1110,
+    0b0111_0111,
+    0b0000_1110,
+    0b0001_1100,
+    0b0011_1011,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::BlackSquare
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // Char::NoBreakSpace
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+];
+
+// End of file
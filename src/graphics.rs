@@ -0,0 +1,124 @@
+//! Support for the `embedded-graphics` crate (with the `embedded_graphics`
+//! feature).
+//!
+//! * `Mode2Canvas` is a `DrawTarget` with `BinaryColor` pixels. The colours
+//!   you actually see come from the text attributes, as usual for Mode 2.
+//! * `FrameBuffer` is a `DrawTarget` for the 48 x 36 text grid, where each
+//!   character cell is one pixel. Drawing a pixel changes the background
+//!   `Colour` of that cell and leaves the character alone.
+//!
+//! `Colour` can be converted from `Rgb888` and `BinaryColor`, so images and
+//! styles in those colour types can be mapped onto the text grid.
+
+use core::convert::Infallible;
+
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::pixelcolor::{BinaryColor, PixelColor, Rgb888, RgbColor};
+use embedded_graphics_core::Pixel;
+
+use super::{
+    Col, Colour, FrameBuffer, Hardware, Ink, Mode2Canvas, Position, Row, MODE0_TEXT_NUM_COLS,
+    MODE0_TEXT_NUM_ROWS,
+};
+
+impl PixelColor for Colour {
+    type Raw = ();
+}
+
+impl From<BinaryColor> for Colour {
+    fn from(colour: BinaryColor) -> Colour {
+        match colour {
+            BinaryColor::On => Colour::White,
+            BinaryColor::Off => Colour::Black,
+        }
+    }
+}
+
+impl From<Rgb888> for Colour {
+    /// Each channel is either on or off, so anything at half brightness or
+    /// more counts as on.
+    fn from(colour: Rgb888) -> Colour {
+        let bits = ((colour.r() >> 7) << 2) | ((colour.g() >> 7) << 1) | (colour.b() >> 7);
+        match bits {
+            0 => Colour::Black,
+            1 => Colour::Blue,
+            2 => Colour::Green,
+            3 => Colour::Cyan,
+            4 => Colour::Red,
+            5 => Colour::Magenta,
+            6 => Colour::Yellow,
+            _ => Colour::White,
+        }
+    }
+}
+
+impl<'a> OriginDimensions for Mode2Canvas<'a> {
+    fn size(&self) -> Size {
+        Size::new(self.width() as u32, self.height() as u32)
+    }
+}
+
+impl<'a> DrawTarget for Mode2Canvas<'a> {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, colour) in pixels {
+            let ink = match colour {
+                BinaryColor::On => Ink::On,
+                BinaryColor::Off => Ink::Off,
+            };
+            self.set_pixel(point.x as isize, point.y as isize, ink);
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, colour: Self::Color) -> Result<(), Self::Error> {
+        Mode2Canvas::clear(self, colour.is_on());
+        Ok(())
+    }
+}
+
+impl<T> OriginDimensions for FrameBuffer<T>
+where
+    T: Hardware,
+{
+    fn size(&self) -> Size {
+        Size::new(MODE0_TEXT_NUM_COLS as u32, MODE0_TEXT_NUM_ROWS as u32)
+    }
+}
+
+impl<T> DrawTarget for FrameBuffer<T>
+where
+    T: Hardware,
+{
+    type Color = Colour;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, colour) in pixels {
+            if point.x < 0
+                || point.y < 0
+                || point.x >= MODE0_TEXT_NUM_COLS as i32
+                || point.y >= MODE0_TEXT_NUM_ROWS as i32
+            {
+                continue;
+            }
+            let pos = Position::new(Row(point.y as u8), Col(point.x as u8));
+            if let Some((_, mut attr)) = self.read_glyph_at(pos) {
+                attr.set_bg(colour);
+                self.set_attr_at(pos, attr);
+            }
+        }
+        Ok(())
+    }
+}
+
+// End of file
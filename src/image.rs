@@ -0,0 +1,717 @@
+//! Converting images into Mode 2 buffers.
+//!
+//! Nothing here allocates, so it works on the device (e.g. on an image read
+//! from an SD card) as well as on a host, where you might prepare buffers
+//! ahead of time. `Image::parse` understands:
+//!
+//! * Binary Netpbm files - bitmaps (PBM, `P4`), greyscale (PGM, `P5`) and
+//!   colour (PPM, `P6`), with up to 8 bits per channel.
+//! * X BitMaps (XBM), as C source.
+//! * Uncompressed Windows BMP files, with 1, 4, 8, 24 or 32 bits per pixel.
+//!
+//! `to_mode2` stretches the image to fill a whole Mode 2 buffer
+//! (`MODE2_WIDTH_PIXELS` by `MODE2_USABLE_LINES`) and dithers it down to one
+//! bit per pixel, with bright pixels set. `suggest_attrs` then picks
+//! colours for each block of pixels, for `FrameBuffer::mode2_region_attrs`,
+//! to get back some of the colour:
+//!
+//! ```ignore
+//! let image = Image::parse(include_bytes!("logo.bmp"))?;
+//! image::to_mode2(&image, Dither::FloydSteinberg, &mut PIXELS)?;
+//! image::suggest_attrs(&image, &PIXELS, &mut ATTRS, 8)?;
+//! fb.mode2(&PIXELS, 0);
+//! fb.mode2_region_attrs(0, &ATTRS, 8);
+//! ```
+//!
+//! Both functions decode the image a row at a time, and use about 2 KiB of
+//! stack.
+
+use super::{Attr, Colour, MODE0_USABLE_HORIZONTAL_OCTETS, MODE2_USABLE_LINES, MODE2_WIDTH_PIXELS};
+
+/// How big a whole-screen Mode 2 buffer is.
+const BUFFER_LEN: usize = MODE0_USABLE_HORIZONTAL_OCTETS * MODE2_USABLE_LINES;
+
+/// Thresholds for ordered dithering, out of 16.
+static BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// The `Colour` for each combination of red, green and blue bits.
+static COLOURS: [Colour; 8] = [
+    Colour::Black,
+    Colour::Blue,
+    Colour::Green,
+    Colour::Cyan,
+    Colour::Red,
+    Colour::Magenta,
+    Colour::Yellow,
+    Colour::White,
+];
+
+/// One pixel, as red, green and blue.
+type Rgb = [u8; 3];
+
+/// Why an image couldn't be read.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ImageError {
+    /// This isn't a format we understand (or it's compressed, or has more
+    /// than 8 bits per channel).
+    Unsupported,
+    /// The header doesn't make sense.
+    Malformed,
+    /// The file stops before the end of the image.
+    Truncated,
+}
+
+/// How to turn shades of grey into pixels which are either on or off.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Dither {
+    /// Pixels are on if they are more than half bright. Good for line art.
+    Threshold,
+    /// Compare with a repeating 4x4 pattern of thresholds. Gives a regular
+    /// cross-hatch, which looks tidy and doesn't shimmer if the image
+    /// changes.
+    Ordered,
+    /// Spread each pixel's error over its neighbours. Best for photos.
+    FloydSteinberg,
+}
+
+/// An image in one of the formats we understand. See the module
+/// documentation.
+pub struct Image<'a> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    kind: Kind,
+}
+
+/// Where to find the pixels, for each format.
+enum Kind {
+    Pbm {
+        offset: usize,
+    },
+    Pgm {
+        offset: usize,
+        max: u32,
+    },
+    Ppm {
+        offset: usize,
+        max: u32,
+    },
+    Xbm {
+        offset: usize,
+    },
+    Bmp {
+        offset: usize,
+        bits: usize,
+        stride: usize,
+        bottom_up: bool,
+        palette: usize,
+        palette_len: usize,
+    },
+}
+
+/// Reads an image a row at a time, scaled to the width of Mode 2. Lines
+/// must be read from top to bottom.
+struct RowReader<'i, 'a: 'i> {
+    image: &'i Image<'a>,
+    // For XBM, where the next row starts in the text, and which row it is
+    text_pos: usize,
+    text_row: usize,
+    // The image row in `row`, so we don't decode it again when scaling up
+    last_row: Option<usize>,
+    row: [Rgb; MODE2_WIDTH_PIXELS],
+}
+
+impl<'a> Image<'a> {
+    /// Work out what sort of image `data` holds, and check it's all there.
+    pub fn parse(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        if data.starts_with(b"P4") || data.starts_with(b"P5") || data.starts_with(b"P6") {
+            Self::parse_netpbm(data)
+        } else if data.starts_with(b"BM") {
+            Self::parse_bmp(data)
+        } else if data.starts_with(b"#define") {
+            Self::parse_xbm(data)
+        } else {
+            Err(ImageError::Unsupported)
+        }
+    }
+
+    /// The width of the image, in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the image, in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn parse_netpbm(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        let mut pos = 2;
+        let width = header_number(data, &mut pos)?;
+        let height = header_number(data, &mut pos)?;
+        let max = if data[1] == b'4' {
+            1
+        } else {
+            header_number(data, &mut pos)? as u32
+        };
+        if max == 0 || max > 255 {
+            return Err(ImageError::Unsupported);
+        }
+        // Exactly one whitespace character before the pixels
+        let offset = pos + 1;
+        let (kind, len) = match data[1] {
+            b'4' => (Kind::Pbm { offset }, mul(width.div_ceil(8), height)?),
+            b'5' => (Kind::Pgm { offset, max }, mul(width, height)?),
+            _ => (Kind::Ppm { offset, max }, mul(mul(width, height)?, 3)?),
+        };
+        Self::checked(data, width, height, kind, add(offset, len)?)
+    }
+
+    fn parse_xbm(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        let mut pos = find(data, b"_width").ok_or(ImageError::Malformed)?;
+        let width = next_number(data, &mut pos)? as usize;
+        pos = find(data, b"_height").ok_or(ImageError::Malformed)?;
+        let height = next_number(data, &mut pos)? as usize;
+        let offset = find(data, b"{").ok_or(ImageError::Malformed)?;
+        // We can't tell if it's all there without reading it, but every byte
+        // takes at least one digit
+        let len = add(offset, mul(width.div_ceil(8), height)?)?;
+        Self::checked(data, width, height, Kind::Xbm { offset }, len)
+    }
+
+    fn parse_bmp(data: &'a [u8]) -> Result<Image<'a>, ImageError> {
+        let offset = read_u32(data, 10)? as usize;
+        let header_len = read_u32(data, 14)? as usize;
+        if header_len < 40 {
+            // Only OS/2 uses the older, shorter header
+            return Err(ImageError::Unsupported);
+        }
+        let width = read_u32(data, 18)? as i32;
+        let height = read_u32(data, 22)? as i32;
+        let bits = read_u16(data, 28)? as usize;
+        let compression = read_u32(data, 30)?;
+        let colours_used = read_u32(data, 46)? as usize;
+        if compression != 0 {
+            return Err(ImageError::Unsupported);
+        }
+        let palette_len = match bits {
+            1 | 4 | 8 if colours_used == 0 => 1 << bits,
+            1 | 4 | 8 => colours_used.min(1 << bits),
+            24 | 32 => 0,
+            _ => return Err(ImageError::Unsupported),
+        };
+        let palette = add(14, header_len)?;
+        if add(palette, palette_len * 4)? > data.len() {
+            return Err(ImageError::Truncated);
+        }
+        if width <= 0 || height == 0 {
+            return Err(ImageError::Malformed);
+        }
+        let (width, bottom_up) = (width as usize, height > 0);
+        let height = height.unsigned_abs() as usize;
+        let stride = mul(width, bits)?.div_ceil(32) * 4;
+        let kind = Kind::Bmp {
+            offset,
+            bits,
+            stride,
+            bottom_up,
+            palette,
+            palette_len,
+        };
+        Self::checked(
+            data,
+            width,
+            height,
+            kind,
+            add(offset, mul(stride, height)?)?,
+        )
+    }
+
+    /// Make an `Image` if it has some pixels, and `data` is at least `len`
+    /// bytes long. Once this has passed, no index into `data` can overflow.
+    fn checked(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        kind: Kind,
+        len: usize,
+    ) -> Result<Image<'a>, ImageError> {
+        if width == 0 || height == 0 {
+            Err(ImageError::Malformed)
+        } else if data.len() < len {
+            Err(ImageError::Truncated)
+        } else {
+            Ok(Image {
+                data,
+                width,
+                height,
+                kind,
+            })
+        }
+    }
+
+    /// Get one pixel. Not for XBM images, which can only be read in order.
+    fn pixel(&self, x: usize, y: usize) -> Rgb {
+        let data = self.data;
+        match self.kind {
+            Kind::Pbm { offset } => {
+                let byte = data[offset + (y * self.width.div_ceil(8)) + (x / 8)];
+                // Set bits are black
+                if byte & (0x80 >> (x % 8)) != 0 {
+                    [0; 3]
+                } else {
+                    [0xFF; 3]
+                }
+            }
+            Kind::Pgm { offset, max } => {
+                let value = scale(data[offset + (y * self.width) + x], max);
+                [value; 3]
+            }
+            Kind::Ppm { offset, max } => {
+                let start = offset + (((y * self.width) + x) * 3);
+                [
+                    scale(data[start], max),
+                    scale(data[start + 1], max),
+                    scale(data[start + 2], max),
+                ]
+            }
+            Kind::Bmp {
+                offset,
+                bits,
+                stride,
+                bottom_up,
+                palette,
+                palette_len,
+            } => {
+                let row = if bottom_up { self.height - 1 - y } else { y };
+                let start = offset + (row * stride);
+                if bits > 8 {
+                    // Stored blue, green, red
+                    let start = start + (x * (bits / 8));
+                    return [data[start + 2], data[start + 1], data[start]];
+                }
+                let bit = x * bits;
+                let shift = 8 - bits - (bit % 8);
+                let index = (data[start + (bit / 8)] as usize >> shift) & ((1 << bits) - 1);
+                if index < palette_len {
+                    let entry = palette + (index * 4);
+                    [data[entry + 2], data[entry + 1], data[entry]]
+                } else {
+                    [0; 3]
+                }
+            }
+            Kind::Xbm { .. } => unreachable!(),
+        }
+    }
+}
+
+impl<'i, 'a> RowReader<'i, 'a> {
+    fn new(image: &'i Image<'a>) -> RowReader<'i, 'a> {
+        let text_pos = match image.kind {
+            Kind::Xbm { offset } => offset,
+            _ => 0,
+        };
+        RowReader {
+            image,
+            text_pos,
+            text_row: 0,
+            last_row: None,
+            row: [[0; 3]; MODE2_WIDTH_PIXELS],
+        }
+    }
+
+    /// Get the pixels for one line of the Mode 2 buffer.
+    fn read(&mut self, line: usize) -> Result<&[Rgb; MODE2_WIDTH_PIXELS], ImageError> {
+        let y = stretch(line, self.image.height, MODE2_USABLE_LINES);
+        if self.last_row != Some(y) {
+            if let Kind::Xbm { .. } = self.image.kind {
+                self.read_xbm(y)?;
+            } else {
+                for (x, pixel) in self.row.iter_mut().enumerate() {
+                    *pixel = self
+                        .image
+                        .pixel(stretch(x, self.image.width, MODE2_WIDTH_PIXELS), y);
+                }
+            }
+            self.last_row = Some(y);
+        }
+        Ok(&self.row)
+    }
+
+    /// XBM data is text, so we have to read through it in order.
+    fn read_xbm(&mut self, y: usize) -> Result<(), ImageError> {
+        let data = self.image.data;
+        let row_len = self.image.width.div_ceil(8);
+        while self.text_row < y {
+            for _ in 0..row_len {
+                next_number(data, &mut self.text_pos)?;
+            }
+            self.text_row += 1;
+        }
+        // How many bytes of this row we've read, and the last one
+        let mut read = 0;
+        let mut byte = 0;
+        for (x, pixel) in self.row.iter_mut().enumerate() {
+            let source_x = stretch(x, self.image.width, MODE2_WIDTH_PIXELS);
+            while read <= source_x / 8 {
+                byte = next_number(data, &mut self.text_pos)?;
+                read += 1;
+            }
+            // Least significant bit first, and set bits are black
+            *pixel = if byte & (1 << (source_x % 8)) != 0 {
+                [0; 3]
+            } else {
+                [0xFF; 3]
+            };
+        }
+        for _ in read..row_len {
+            next_number(data, &mut self.text_pos)?;
+        }
+        self.text_row += 1;
+        Ok(())
+    }
+}
+
+/// Fill a Mode 2 buffer with `image`, stretched to fit and dithered. Pixels
+/// are set where the image is bright.
+///
+/// Panics if `buffer` is smaller than `MODE0_USABLE_HORIZONTAL_OCTETS *
+/// MODE2_USABLE_LINES` bytes.
+pub fn to_mode2(image: &Image, dither: Dither, buffer: &mut [u8]) -> Result<(), ImageError> {
+    assert!(buffer.len() >= BUFFER_LEN);
+    let mut reader = RowReader::new(image);
+    // Floyd-Steinberg errors for this line and the next, with a spare
+    // entry at each end so we don't have to check the edges
+    let mut errors = [[0i16; MODE2_WIDTH_PIXELS + 2]; 2];
+    for line in 0..MODE2_USABLE_LINES {
+        let row = reader.read(line)?;
+        let (this, next) = errors.split_at_mut(1);
+        let (this, next) = (&mut this[0], &mut next[0]);
+        for (x, pixel) in row.iter().enumerate() {
+            let luminance = luminance(*pixel);
+            let on = match dither {
+                Dither::Threshold => luminance >= 128,
+                Dither::Ordered => luminance >= (BAYER[line % 4][x % 4] as i16 * 16) + 8,
+                Dither::FloydSteinberg => {
+                    let value = luminance + this[x + 1];
+                    let on = value >= 128;
+                    let error = value - if on { 255 } else { 0 };
+                    this[x + 2] += (error * 7) / 16;
+                    next[x] += (error * 3) / 16;
+                    next[x + 1] += (error * 5) / 16;
+                    next[x + 2] += error / 16;
+                    on
+                }
+            };
+            let byte = &mut buffer[(line * MODE0_USABLE_HORIZONTAL_OCTETS) + (x / 8)];
+            let mask = 0x80 >> (x % 8);
+            if on {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        }
+        *this = [0; MODE2_WIDTH_PIXELS + 2];
+        errors.swap(0, 1);
+    }
+    Ok(())
+}
+
+/// Pick colours for each 8 pixel wide, `block_lines` high block of a Mode 2
+/// buffer made from `image` by `to_mode2`, for
+/// `FrameBuffer::mode2_region_attrs`. The foreground is the average colour
+/// of the image where the block's pixels are set, and the background is the
+/// average where they're clear, each rounded to the nearest `Colour`.
+///
+/// Panics if `buffer` isn't a whole Mode 2 buffer, `attrs` is too short or
+/// `block_lines` is 0.
+pub fn suggest_attrs(
+    image: &Image,
+    buffer: &[u8],
+    attrs: &mut [Attr],
+    block_lines: usize,
+) -> Result<(), ImageError> {
+    assert!(block_lines > 0);
+    assert!(buffer.len() >= BUFFER_LEN);
+    assert!(
+        attrs.len() >= MODE2_USABLE_LINES.div_ceil(block_lines) * MODE0_USABLE_HORIZONTAL_OCTETS
+    );
+    let mut reader = RowReader::new(image);
+    // For each block in the row, the total red, green and blue, and the
+    // number of pixels, for clear and then set pixels
+    let mut totals = [[[0u32; 4]; 2]; MODE0_USABLE_HORIZONTAL_OCTETS];
+    for line in 0..MODE2_USABLE_LINES {
+        let row = reader.read(line)?;
+        for (x, pixel) in row.iter().enumerate() {
+            let byte = buffer[(line * MODE0_USABLE_HORIZONTAL_OCTETS) + (x / 8)];
+            let on = (byte & (0x80 >> (x % 8)) != 0) as usize;
+            let total = &mut totals[x / 8][on];
+            for (sum, &channel) in total.iter_mut().zip(pixel.iter()) {
+                *sum += channel as u32;
+            }
+            total[3] += 1;
+        }
+        if (line % block_lines) == block_lines - 1 || line == MODE2_USABLE_LINES - 1 {
+            let block_row = line / block_lines;
+            for (col, total) in totals.iter_mut().enumerate() {
+                let bg = nearest_colour(&total[0]);
+                let fg = nearest_colour(&total[1]);
+                // A block which is all one or the other only needs one colour
+                let (fg, bg) = (fg.or(bg), bg.or(fg));
+                attrs[(block_row * MODE0_USABLE_HORIZONTAL_OCTETS) + col] =
+                    Attr::new(fg.unwrap_or(Colour::White), bg.unwrap_or(Colour::Black));
+                *total = [[0; 4]; 2];
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Round the average of some pixels (red, green and blue totals, then how
+/// many there were) to a `Colour`. `None` if there weren't any.
+fn nearest_colour(total: &[u32; 4]) -> Option<Colour> {
+    if total[3] == 0 {
+        return None;
+    }
+    let bit = |channel: usize| (total[channel] / total[3] >= 128) as usize;
+    Some(COLOURS[(bit(0) << 2) | (bit(1) << 1) | bit(2)])
+}
+
+/// How bright a pixel is, from 0 to 255, using the BT.601 weights.
+fn luminance(pixel: Rgb) -> i16 {
+    (((pixel[0] as u32 * 77) + (pixel[1] as u32 * 150) + (pixel[2] as u32 * 29)) >> 8) as i16
+}
+
+/// Stretch a Netpbm sample with the given maximum to 0..=255.
+fn scale(value: u8, max: u32) -> u8 {
+    ((value as u32 * 255) / max).min(255) as u8
+}
+
+/// Which of `len` source pixels to use for pixel `index` of `out`. Done in
+/// 64 bits, as the image may be bigger than 32 bits can multiply.
+fn stretch(index: usize, len: usize, out: usize) -> usize {
+    ((index as u64 * len as u64) / out as u64) as usize
+}
+
+/// Multiply two sizes from a header, which may be nonsense.
+fn mul(a: usize, b: usize) -> Result<usize, ImageError> {
+    a.checked_mul(b).ok_or(ImageError::Malformed)
+}
+
+/// Add two sizes from a header, which may be nonsense.
+fn add(a: usize, b: usize) -> Result<usize, ImageError> {
+    a.checked_add(b).ok_or(ImageError::Malformed)
+}
+
+/// Read a decimal number from a Netpbm header, skipping any whitespace and
+/// comments before it.
+fn header_number(data: &[u8], pos: &mut usize) -> Result<usize, ImageError> {
+    loop {
+        match data.get(*pos) {
+            Some(b'#') => {
+                while data.get(*pos).ok_or(ImageError::Truncated)? != &b'\n' {
+                    *pos += 1;
+                }
+            }
+            Some(byte) if byte.is_ascii_whitespace() => *pos += 1,
+            Some(byte) if byte.is_ascii_digit() => break,
+            Some(_) => return Err(ImageError::Malformed),
+            None => return Err(ImageError::Truncated),
+        }
+    }
+    let mut value: usize = 0;
+    while let Some(byte) = data.get(*pos).filter(|b| b.is_ascii_digit()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((byte - b'0') as usize))
+            .ok_or(ImageError::Malformed)?;
+        *pos += 1;
+    }
+    Ok(value)
+}
+
+/// Read the next number from some C source, in decimal or `0x` hex,
+/// skipping anything before it.
+fn next_number(data: &[u8], pos: &mut usize) -> Result<u32, ImageError> {
+    while !data
+        .get(*pos)
+        .ok_or(ImageError::Truncated)?
+        .is_ascii_digit()
+    {
+        *pos += 1;
+    }
+    let radix = match data.get(*pos + 1) {
+        Some(b'x') | Some(b'X') if data[*pos] == b'0' => {
+            *pos += 2;
+            16
+        }
+        _ => 10,
+    };
+    let mut value: u32 = 0;
+    while let Some(digit) = data.get(*pos).and_then(|&b| (b as char).to_digit(radix)) {
+        value = value.wrapping_mul(radix).wrapping_add(digit);
+        *pos += 1;
+    }
+    Ok(value)
+}
+
+/// Find where `needle` ends in `data`.
+fn find(data: &[u8], needle: &[u8]) -> Option<usize> {
+    data.windows(needle.len())
+        .position(|window| window == needle)
+        .map(|start| start + needle.len())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ImageError> {
+    match data.get(offset..offset + 2) {
+        Some(bytes) => Ok(u16::from_le_bytes([bytes[0], bytes[1]])),
+        None => Err(ImageError::Truncated),
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, ImageError> {
+    match data.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        None => Err(ImageError::Truncated),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 2x2 24-bit BMP: white, black on the bottom row and red, blue on
+    /// the top.
+    fn bmp() -> [u8; 70] {
+        let mut data = [0u8; 70];
+        data[0..2].copy_from_slice(b"BM");
+        data[2..6].copy_from_slice(&70u32.to_le_bytes());
+        data[10..14].copy_from_slice(&54u32.to_le_bytes());
+        data[14..18].copy_from_slice(&40u32.to_le_bytes());
+        data[18..22].copy_from_slice(&2u32.to_le_bytes());
+        data[22..26].copy_from_slice(&2u32.to_le_bytes());
+        data[26..28].copy_from_slice(&1u16.to_le_bytes());
+        data[28..30].copy_from_slice(&24u16.to_le_bytes());
+        // Rows are padded to 8 bytes
+        data[54..60].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0, 0, 0]);
+        data[62..68].copy_from_slice(&[0, 0, 0xFF, 0xFF, 0, 0]);
+        data
+    }
+
+    const XBM: &[u8] = b"#define t_width 9\n#define t_height 2\n\
+        static char t_bits[] = {\n0x01, 0x00, 0xff, 0x01 };\n";
+
+    #[test]
+    fn parse_netpbm() {
+        let image = Image::parse(b"P4\n9 2\n\x80\x00\xff\x80").unwrap();
+        assert_eq!((image.width(), image.height()), (9, 2));
+        assert_eq!(image.pixel(0, 0), [0; 3]);
+        assert_eq!(image.pixel(1, 0), [0xFF; 3]);
+        assert_eq!(image.pixel(8, 1), [0; 3]);
+
+        let image = Image::parse(b"P5 # comment\n2 1 15\n\x0f\x00").unwrap();
+        assert_eq!(image.pixel(0, 0), [0xFF; 3]);
+        assert_eq!(image.pixel(1, 0), [0; 3]);
+
+        let image = Image::parse(b"P6 1 1 255 \x10\x20\x30").unwrap();
+        assert_eq!(image.pixel(0, 0), [0x10, 0x20, 0x30]);
+    }
+
+    #[test]
+    fn parse_xbm() {
+        let image = Image::parse(XBM).unwrap();
+        assert_eq!((image.width(), image.height()), (9, 2));
+        let mut reader = RowReader::new(&image);
+        let row = reader.read(0).unwrap();
+        assert_eq!(row[0], [0; 3]);
+        assert_eq!(row[MODE2_WIDTH_PIXELS - 1], [0xFF; 3]);
+        let row = reader.read(MODE2_USABLE_LINES - 1).unwrap();
+        assert_eq!(row[0], [0; 3]);
+        assert_eq!(row[MODE2_WIDTH_PIXELS - 1], [0; 3]);
+    }
+
+    #[test]
+    fn parse_bmp() {
+        let data = bmp();
+        let image = Image::parse(&data).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.pixel(0, 0), [0xFF, 0, 0]);
+        assert_eq!(image.pixel(1, 0), [0, 0, 0xFF]);
+        assert_eq!(image.pixel(0, 1), [0xFF; 3]);
+        assert_eq!(image.pixel(1, 1), [0; 3]);
+    }
+
+    #[test]
+    fn truncated() {
+        for data in &[
+            &b"P4\n9 2\n\x80\x00\xff"[..],
+            &b"P5 2 1 15\n\x0f"[..],
+            &b"P6 1 1 255 \x10\x20"[..],
+            &b"P6 1 1"[..],
+            &b"P5"[..],
+            &bmp()[..69],
+            &bmp()[..20],
+        ] {
+            assert_eq!(Image::parse(data).err(), Some(ImageError::Truncated));
+        }
+        // XBM can only tell once it tries to read the pixels
+        let image = Image::parse(&XBM[..XBM.len() - 8]).unwrap();
+        let mut buffer = [0u8; BUFFER_LEN];
+        assert_eq!(
+            to_mode2(&image, Dither::Threshold, &mut buffer),
+            Err(ImageError::Truncated)
+        );
+    }
+
+    #[test]
+    fn oversized() {
+        for data in &[
+            &b"P4 18446744073709551615 16 "[..],
+            &b"P5 4294967296 4294967296 255 "[..],
+            &b"P6 3037000500 3037000500 255 "[..],
+            &b"P5 99999999999999999999 1 255 "[..],
+        ] {
+            assert_eq!(Image::parse(data).err(), Some(ImageError::Malformed));
+        }
+        let data = b"#define t_width 4294967295\n#define t_height 4294967295\n{ 0 };";
+        assert!(Image::parse(data).is_err());
+        for &(width, height, offset) in &[
+            (0x7FFF_FFFFu32, 0x7FFF_FFFFu32, 54u32),
+            (2, 0x8000_0000, 0xFFFF_FFFF),
+            (2, 2, 0xFFFF_FFFF),
+        ] {
+            let mut data = bmp();
+            data[10..14].copy_from_slice(&offset.to_le_bytes());
+            data[18..22].copy_from_slice(&width.to_le_bytes());
+            data[22..26].copy_from_slice(&height.to_le_bytes());
+            data[28..30].copy_from_slice(&32u16.to_le_bytes());
+            assert!(Image::parse(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn convert() {
+        let data = bmp();
+        let image = Image::parse(&data).unwrap();
+        let mut buffer = [0u8; BUFFER_LEN];
+        to_mode2(&image, Dither::Threshold, &mut buffer).unwrap();
+        // Red and blue are dark; white is bright
+        assert_eq!(buffer[0], 0x00);
+        assert_eq!(buffer[BUFFER_LEN - MODE0_USABLE_HORIZONTAL_OCTETS], 0xFF);
+        assert_eq!(buffer[BUFFER_LEN - 1], 0x00);
+        let mut attrs =
+            [Attr::new(Colour::Black, Colour::Black); 2 * MODE0_USABLE_HORIZONTAL_OCTETS];
+        suggest_attrs(&image, &buffer, &mut attrs, MODE2_USABLE_LINES / 2).unwrap();
+        assert_eq!(attrs[0], Attr::new(Colour::Red, Colour::Red));
+        assert_eq!(
+            attrs[MODE0_USABLE_HORIZONTAL_OCTETS],
+            Attr::new(Colour::White, Colour::White)
+        );
+        assert_eq!(
+            attrs[2 * MODE0_USABLE_HORIZONTAL_OCTETS - 1],
+            Attr::new(Colour::Black, Colour::Black)
+        );
+    }
+}
+
+// End of file
@@ -0,0 +1,4387 @@
+/// The font data here must be in `CodePage::Iso8859_1` order. This is not
+/// FreeBSD's iso-8x16 font. Every printable Latin-1 character is also in Code
+/// Page 850, so this is the cp850-8x16 font from FreeBSD with its glyphs in a
+/// different order. The C1 control codes (0x80..0x9F) are blank. See
+/// http://web.mit.edu/freebsd/head/share/syscons/fonts/cp850-8x16.fnt
+///
+/// The compilation of software known as FreeBSD is distributed under the
+/// following terms:
+///
+/// Copyright (c) 1992-2014 The FreeBSD Project. All rights reserved.
+///
+/// Redistribution and use in source and binary forms, with or without
+/// modification, are permitted provided that the following conditions
+/// are met:
+/// 1. Redistributions of source code must retain the above copyright
+///    notice, this list of conditions and the following disclaimer.
+/// 2. Redistributions in binary form must reproduce the above copyright
+///    notice, this list of conditions and the following disclaimer in the
+///    documentation and/or other materials provided with the distribution.
+///
+/// THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+/// ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+/// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+/// ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+/// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+/// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+/// OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+/// HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+/// LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+/// OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+/// SUCH DAMAGE.
+pub static FONT_DATA: [u8; 256 * super::MAX_FONT_HEIGHT] = [
+    // ' '
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☺'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1000_0001,
+    0b1010_0101,
+    0b1000_0001,
+    0b1000_0001,
+    0b1011_1101,
+    0b1001_1001,
+    0b1000_0001,
+    0b1000_0001,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☻'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1111_1111,
+    0b1101_1011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1100_0011,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♥'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_1100,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b0111_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♦'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0111_1100,
+    0b1111_1110,
+    0b0111_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♣'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♠'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '•'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◘'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1100_0011,
+    0b1100_0011,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '○'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0100_0010,
+    0b0100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◙'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1100_0011,
+    0b1001_1001,
+    0b1011_1101,
+    0b1011_1101,
+    0b1001_1001,
+    0b1100_0011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '♂'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0000_1110,
+    0b0001_1010,
+    0b0011_0010,
+    0b0111_1000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♀'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♪'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_0011,
+    0b0011_1111,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0111_0000,
+    0b1111_0000,
+    0b1110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♫'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0110_0011,
+    0b0111_1111,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0111,
+    0b1110_0111,
+    0b1110_0110,
+    0b1100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1101_1011,
+    0b0011_1100,
+    0b1110_0111,
+    0b0011_1100,
+    0b1101_1011,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '►'
+    0b0000_0000,
+    0b1000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b1111_0000,
+    0b1111_1000,
+    0b1111_1110,
+    0b1111_1000,
+    0b1111_0000,
+    0b1110_0000,
+    0b1100_0000,
+    0b1000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◄'
+    0b0000_0000,
+    0b0000_0010,
+    0b0000_0110,
+    0b0000_1110,
+    0b0001_1110,
+    0b0011_1110,
+    0b1111_1110,
+    0b0011_1110,
+    0b0001_1110,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↕'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '‼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¶'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1101_1011,
+    0b1101_1011,
+    0b1101_1011,
+    0b0111_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '§'
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_1100,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▬'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↨'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↑'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↓'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '→'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b1111_1110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '←'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1111_1110,
+    0b0110_0000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '∟'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↔'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0010_1000,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0010_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▲'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0111_1100,
+    0b1111_1110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1111_1110,
+    0b0111_1100,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ' '
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '!'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '"'
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0010_0100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '#'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_1100,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '$'
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b0111_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b1000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    // '%'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0010,
+    0b1100_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0110,
+    0b1000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '&'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1000,
+    0b0111_0110,
+    0b1101_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\''
+    0b0000_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '('
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ')'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '*'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0011_1100,
+    0b1111_1111,
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '+'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ','
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '-'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '.'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '/'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0010,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '0'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '1'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1000,
+    0b0111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '2'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '3'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0011_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '4'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1100,
+    0b0011_1100,
+    0b0110_1100,
+    0b1100_1100,
+    0b1111_1110,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '5'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '6'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '7'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '8'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '9'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ':'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ';'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '<'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '='
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '>'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '?'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '@'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_1110,
+    0b1101_1110,
+    0b1101_1110,
+    0b1101_1100,
+    0b1100_0000,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'A'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'B'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'C'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'D'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'E'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'F'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'G'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1101_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_0110,
+    0b0011_1010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'H'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'I'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'J'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'K'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b0111_1000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'L'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'M'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'N'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_0110,
+    0b1111_0110,
+    0b1111_1110,
+    0b1101_1110,
+    0b1100_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'O'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'P'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Q'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_1110,
+    0b0111_1100,
+    0b0000_1100,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    // 'R'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'S'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'T'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0111_1110,
+    0b0101_1010,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'U'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'V'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'W'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b1110_1110,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'X'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Y'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Z'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b1000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0010,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '['
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\\'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ']'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '^'
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '_'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    // '`'
+    0b0000_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'a'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'b'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'c'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'd'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0011_1100,
+    0b0110_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'e'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'f'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_0110,
+    0b0011_0010,
+    0b0011_0000,
+    0b0111_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'g'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1100,
+    0b0000_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    // 'h'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_1100,
+    0b0111_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'i'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'j'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    // 'k'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0110,
+    0b0110_1100,
+    0b0111_1000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'l'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'm'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1100,
+    0b1111_1110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'n'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'o'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'p'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    // 'q'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1110,
+    0b0000_0000,
+    // 'r'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0111_0110,
+    0b0110_0110,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 's'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0000_1100,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 't'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b1111_1100,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'u'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'v'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'w'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'x'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'y'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_1100,
+    0b1111_1000,
+    0b0000_0000,
+    // 'z'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '{'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '|'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '~'
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{7f}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{80}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{81}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{82}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{83}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{84}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{85}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{86}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{87}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{88}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{89}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8a}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8b}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8c}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8d}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8e}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{8f}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{90}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{91}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{92}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{93}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{94}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{95}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{96}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{97}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{98}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{99}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9a}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9b}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9c}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9d}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9e}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{9f}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{a0}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¡'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¢'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '£'
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_0100,
+    0b0110_0000,
+    0b1111_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1110_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¤'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¥'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¦'
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '§'
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_1100,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¨'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '©'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1000_0010,
+    0b1001_1010,
+    0b1010_0010,
+    0b1010_0010,
+    0b1010_0010,
+    0b1001_1010,
+    0b1000_0010,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ª'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1110,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '«'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0110,
+    0b0110_1100,
+    0b1101_1000,
+    0b0110_1100,
+    0b0011_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¬'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{ad}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '®'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1000_0010,
+    0b1011_0010,
+    0b1010_1010,
+    0b1011_0010,
+    0b1010_1010,
+    0b1010_1010,
+    0b1000_0010,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¯'
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '°'
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '±'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '²'
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0010,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '³'
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_0110,
+    0b0011_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '´'
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'µ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b0000_0000,
+    // '¶'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1101_1011,
+    0b1101_1011,
+    0b1101_1011,
+    0b0111_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '·'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¸'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¹'
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'º'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '»'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1000,
+    0b0110_1100,
+    0b0011_0110,
+    0b0110_1100,
+    0b1101_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¼'
+    0b0000_0000,
+    0b0110_0000,
+    0b1110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b0110_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0110,
+    0b1100_1110,
+    0b1001_1010,
+    0b0011_1111,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    // '½'
+    0b0000_0000,
+    0b0110_0000,
+    0b1110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b0110_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1101_1100,
+    0b1000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_1110,
+    0b0000_0000,
+    0b0000_0000,
+    // '¾'
+    0b0000_0000,
+    0b1110_0000,
+    0b0011_0000,
+    0b0110_0010,
+    0b0011_0110,
+    0b1110_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0110,
+    0b1100_1110,
+    0b1001_1010,
+    0b0011_1111,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    // '¿'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'À'
+    0b0000_1100,
+    0b0000_0110,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Á'
+    0b0110_0000,
+    0b1100_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Â'
+    0b0111_1100,
+    0b1100_0110,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ã'
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ä'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Å'
+    0b0011_1000,
+    0b0110_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Æ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0110_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1111_1110,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ç'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'È'
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'É'
+    0b0000_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ê'
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ë'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ì'
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Í'
+    0b0000_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Î'
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ï'
+    0b0000_0000,
+    0b0110_0110,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ð'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ñ'
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_0110,
+    0b1111_0110,
+    0b1111_1110,
+    0b1101_1110,
+    0b1100_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ò'
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ó'
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ô'
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Õ'
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ö'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '×'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ø'
+    0b0000_0000,
+    0b0000_0100,
+    0b0111_1100,
+    0b1100_1110,
+    0b1100_1110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1110_0110,
+    0b1110_0110,
+    0b0111_1100,
+    0b0100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ù'
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ú'
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Û'
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ü'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ý'
+    0b0000_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Þ'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0110_0000,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ß'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1101_1000,
+    0b1100_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'à'
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'á'
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'â'
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ã'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ä'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'å'
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'æ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1100,
+    0b0011_0110,
+    0b0011_0110,
+    0b0111_1110,
+    0b1101_1000,
+    0b1101_1000,
+    0b0110_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ç'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0001_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'è'
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'é'
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ê'
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ë'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ì'
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'í'
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'î'
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ï'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ð'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0100,
+    0b0001_1000,
+    0b0010_1100,
+    0b0000_0110,
+    0b0011_1110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ñ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ò'
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ó'
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ô'
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'õ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ö'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '÷'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ø'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_1110,
+    0b1101_1110,
+    0b1111_0110,
+    0b1110_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ù'
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ú'
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'û'
+    0b0000_0000,
+    0b0011_0000,
+    0b0111_1000,
+    0b1100_1100,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ü'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ý'
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_1100,
+    0b1111_1000,
+    0b0000_0000,
+    // 'þ'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    // 'ÿ'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0111_1000,
+    0b0000_0000,
+];
+
+// End of file
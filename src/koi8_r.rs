@@ -0,0 +1,4392 @@
+/// The font data here must be in `CodePage::Koi8R` order. This is not
+/// FreeBSD's koi8-r-8x16 font. The ASCII characters, the box drawing
+/// characters Code Page 850 has, and the Cyrillic letters which look like
+/// Latin letters are taken from the cp850-8x16 font from FreeBSD, and the
+/// terms below apply to those. See
+/// http://web.mit.edu/freebsd/head/share/syscons/fonts/cp850-8x16.fnt
+///
+/// The rest of the Cyrillic, and the box drawing characters which Code Page
+/// 850 doesn't have, were drawn for this crate in the same style, and are
+/// not from FreeBSD.
+///
+/// The compilation of software known as FreeBSD is distributed under the
+/// following terms:
+///
+/// Copyright (c) 1992-2014 The FreeBSD Project. All rights reserved.
+///
+/// Redistribution and use in source and binary forms, with or without
+/// modification, are permitted provided that the following conditions
+/// are met:
+/// 1. Redistributions of source code must retain the above copyright
+///    notice, this list of conditions and the following disclaimer.
+/// 2. Redistributions in binary form must reproduce the above copyright
+///    notice, this list of conditions and the following disclaimer in the
+///    documentation and/or other materials provided with the distribution.
+///
+/// THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+/// ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+/// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+/// ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+/// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+/// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+/// OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+/// HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+/// LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+/// OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+/// SUCH DAMAGE.
+pub static FONT_DATA: [u8; 256 * super::MAX_FONT_HEIGHT] = [
+    // ' '
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☺'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1000_0001,
+    0b1010_0101,
+    0b1000_0001,
+    0b1000_0001,
+    0b1011_1101,
+    0b1001_1001,
+    0b1000_0001,
+    0b1000_0001,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☻'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1111_1111,
+    0b1101_1011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1100_0011,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♥'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_1100,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b0111_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♦'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0111_1100,
+    0b1111_1110,
+    0b0111_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♣'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b1110_0111,
+    0b1110_0111,
+    0b1110_0111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♠'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b1111_1111,
+    0b1111_1111,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '•'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◘'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1110_0111,
+    0b1100_0011,
+    0b1100_0011,
+    0b1110_0111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '○'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0100_0010,
+    0b0100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◙'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1100_0011,
+    0b1001_1001,
+    0b1011_1101,
+    0b1011_1101,
+    0b1001_1001,
+    0b1100_0011,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '♂'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0000_1110,
+    0b0001_1010,
+    0b0011_0010,
+    0b0111_1000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♀'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♪'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_0011,
+    0b0011_1111,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0111_0000,
+    0b1111_0000,
+    0b1110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '♫'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b0110_0011,
+    0b0111_1111,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0011,
+    0b0110_0111,
+    0b1110_0111,
+    0b1110_0110,
+    0b1100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '☼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1101_1011,
+    0b0011_1100,
+    0b1110_0111,
+    0b0011_1100,
+    0b1101_1011,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '►'
+    0b0000_0000,
+    0b1000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b1111_0000,
+    0b1111_1000,
+    0b1111_1110,
+    0b1111_1000,
+    0b1111_0000,
+    0b1110_0000,
+    0b1100_0000,
+    0b1000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '◄'
+    0b0000_0000,
+    0b0000_0010,
+    0b0000_0110,
+    0b0000_1110,
+    0b0001_1110,
+    0b0011_1110,
+    0b1111_1110,
+    0b0011_1110,
+    0b0001_1110,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↕'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '‼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '¶'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1111,
+    0b1101_1011,
+    0b1101_1011,
+    0b1101_1011,
+    0b0111_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0001_1011,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '§'
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_1100,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▬'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↨'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↑'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↓'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '→'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b1111_1110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '←'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1111_1110,
+    0b0110_0000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '∟'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '↔'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0010_1000,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0010_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▲'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0111_1100,
+    0b1111_1110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▼'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1111_1110,
+    0b0111_1100,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ' '
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '!'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0011_1100,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '"'
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0010_0100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '#'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_1100,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b1111_1110,
+    0b0110_1100,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '$'
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b0111_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b1000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    // '%'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0010,
+    0b1100_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0110,
+    0b1000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '&'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1000,
+    0b0111_0110,
+    0b1101_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\''
+    0b0000_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '('
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ')'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '*'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0011_1100,
+    0b1111_1111,
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '+'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ','
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '-'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '.'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '/'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0010,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '0'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '1'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0011_1000,
+    0b0111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '2'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '3'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0011_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '4'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1100,
+    0b0011_1100,
+    0b0110_1100,
+    0b1100_1100,
+    0b1111_1110,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '5'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '6'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '7'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '8'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '9'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ':'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ';'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '<'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '='
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '>'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '?'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '@'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_1110,
+    0b1101_1110,
+    0b1101_1110,
+    0b1101_1100,
+    0b1100_0000,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'A'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'B'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'C'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'D'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'E'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'F'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'G'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1101_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_0110,
+    0b0011_1010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'H'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'I'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'J'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'K'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b0111_1000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'L'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'M'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'N'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_0110,
+    0b1111_0110,
+    0b1111_1110,
+    0b1101_1110,
+    0b1100_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'O'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'P'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Q'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_1110,
+    0b0111_1100,
+    0b0000_1100,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    // 'R'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'S'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0000_1100,
+    0b0000_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'T'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0111_1110,
+    0b0101_1010,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'U'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'V'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'W'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b1110_1110,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'X'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Y'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Z'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b1000_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0010,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '['
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\\'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1000_0000,
+    0b1100_0000,
+    0b1110_0000,
+    0b0111_0000,
+    0b0011_1000,
+    0b0001_1100,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // ']'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '^'
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '_'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    // '`'
+    0b0000_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'a'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'b'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'c'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'd'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0011_1100,
+    0b0110_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'e'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'f'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1100,
+    0b0011_0110,
+    0b0011_0010,
+    0b0011_0000,
+    0b0111_1000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'g'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1100,
+    0b0000_1100,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    // 'h'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_1100,
+    0b0111_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'i'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'j'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    // 'k'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0110,
+    0b0110_1100,
+    0b0111_1000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'l'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'm'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_1100,
+    0b1111_1110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'n'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'o'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'p'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    // 'q'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0001_1110,
+    0b0000_0000,
+    // 'r'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0111_0110,
+    0b0110_0110,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 's'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0110_0000,
+    0b0011_1000,
+    0b0000_1100,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 't'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b1111_1100,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_0110,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'u'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'v'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'w'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b0110_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'x'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'y'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_1100,
+    0b1111_1000,
+    0b0000_0000,
+    // 'z'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '{'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '|'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_1110,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '~'
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{7f}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '─'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '│'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '┌'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '┐'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '└'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '┘'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '├'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '┤'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '┬'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '┴'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '┼'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '▀'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '▄'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '█'
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    0b1111_1111,
+    // '▌'
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    0b1111_0000,
+    // '▐'
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    0b0000_1111,
+    // '░'
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    0b0001_0001,
+    0b0100_0100,
+    // '▒'
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    0b0101_0101,
+    0b1010_1010,
+    // '▓'
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    0b1101_1101,
+    0b0111_0111,
+    // '⌠'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1110,
+    0b0001_1011,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '■'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '∙'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '√'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1111,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b0000_1100,
+    0b1110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1100,
+    0b0001_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '≈'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_0110,
+    0b1101_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '≤'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '≥'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0000,
+    0b0011_0000,
+    0b0001_1000,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0000,
+    0b0110_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '\u{a0}'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '⌡'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1101_1000,
+    0b0111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '°'
+    0b0000_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b0110_1100,
+    0b0011_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '²'
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b0000_1100,
+    0b0001_1000,
+    0b0011_0010,
+    0b0111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '·'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '÷'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0000_0000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '═'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '║'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╒'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // 'ё'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╓'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╔'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1111,
+    0b0011_0000,
+    0b0011_0111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╕'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '╖'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╗'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0000_0110,
+    0b1111_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╘'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╙'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╚'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0111,
+    0b0011_0000,
+    0b0011_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╛'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╜'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╝'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_0110,
+    0b0000_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╞'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '╟'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╠'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0111,
+    0b0011_0000,
+    0b0011_0111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╡'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0001_1000,
+    0b1111_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // 'Ё'
+    0b0000_0000,
+    0b1100_0110,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╢'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╣'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_0110,
+    0b0000_0110,
+    0b1111_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╤'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '╥'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╦'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_0111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╧'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╨'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╩'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_0111,
+    0b0000_0000,
+    0b1111_1111,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // '╪'
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b1111_1111,
+    0b0001_1000,
+    0b1111_1111,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    // '╫'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_1111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '╬'
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b1111_0111,
+    0b0000_0000,
+    0b1111_0111,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    // '©'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1000_0010,
+    0b1001_1010,
+    0b1010_0010,
+    0b1010_0010,
+    0b1010_0010,
+    0b1001_1010,
+    0b1000_0010,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ю'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1101_0010,
+    0b1101_0010,
+    0b1111_0010,
+    0b1101_0010,
+    0b1101_0010,
+    0b1100_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'а'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1000,
+    0b0000_1100,
+    0b0111_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b0111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'б'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_1100,
+    0b0011_0000,
+    0b0110_0000,
+    0b1101_1100,
+    0b1110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ц'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1111_1110,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'д'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b0110_1100,
+    0b1111_1110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'е'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ф'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0001_0000,
+    0b0111_1100,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b0111_1100,
+    0b0001_0000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'г'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'х'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b0110_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'и'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_1110,
+    0b1101_1110,
+    0b1111_0110,
+    0b1110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'й'
+    0b0000_0000,
+    0b0000_0000,
+    0b0100_0100,
+    0b0011_1000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_1110,
+    0b1101_1110,
+    0b1111_0110,
+    0b1110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'к'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_1100,
+    0b1101_1000,
+    0b1111_0000,
+    0b1101_1000,
+    0b1100_1100,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'л'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'м'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_1110,
+    0b1111_1110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'н'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'о'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'п'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'я'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0011_0110,
+    0b0110_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'р'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    // 'с'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'т'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0101_1010,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'у'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_1100,
+    0b1111_1000,
+    0b0000_0000,
+    // 'ж'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b0101_0100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0101_0100,
+    0b1101_0110,
+    0b1101_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'в'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ь'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ы'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'з'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0011_1100,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ш'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'э'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0011_1110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'щ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1111,
+    0b0000_0001,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ч'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'ъ'
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ю'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1101_0010,
+    0b1101_0010,
+    0b1101_0010,
+    0b1111_0010,
+    0b1101_0010,
+    0b1101_0010,
+    0b1101_0010,
+    0b1101_0010,
+    0b1100_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'А'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0011_1000,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Б'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_0000,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ц'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1100_1100,
+    0b1111_1110,
+    0b0000_0010,
+    0b0000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Д'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1000_0010,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Е'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_1000,
+    0b0111_1000,
+    0b0110_1000,
+    0b0110_0000,
+    0b0110_0010,
+    0b0110_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ф'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_0000,
+    0b0111_1100,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b0111_1100,
+    0b0001_0000,
+    0b0001_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Г'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b0110_0110,
+    0b0110_0010,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Х'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b0110_1100,
+    0b0111_1100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0111_1100,
+    0b0110_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'И'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_1110,
+    0b1100_1110,
+    0b1101_1110,
+    0b1111_0110,
+    0b1110_0110,
+    0b1110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Й'
+    0b0100_0100,
+    0b0011_1000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_1110,
+    0b1100_1110,
+    0b1101_1110,
+    0b1111_0110,
+    0b1110_0110,
+    0b1110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'К'
+    0b0000_0000,
+    0b0000_0000,
+    0b1110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_1100,
+    0b0111_1000,
+    0b0111_1000,
+    0b0110_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b1110_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Л'
+    0b0000_0000,
+    0b0000_0000,
+    0b0001_1110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'М'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1110_1110,
+    0b1111_1110,
+    0b1111_1110,
+    0b1101_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Н'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'О'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'П'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Я'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0011_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Р'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b1111_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'С'
+    0b0000_0000,
+    0b0000_0000,
+    0b0011_1100,
+    0b0110_0110,
+    0b1100_0010,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0000,
+    0b1100_0010,
+    0b0110_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Т'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1110,
+    0b0111_1110,
+    0b0101_1010,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0001_1000,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'У'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_1100,
+    0b0111_1000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ж'
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b1101_0110,
+    0b0101_0100,
+    0b0101_0100,
+    0b0011_1000,
+    0b0011_1000,
+    0b0101_0100,
+    0b0101_0100,
+    0b1101_0110,
+    0b1101_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'В'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b1111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ь'
+    0b0000_0000,
+    0b0000_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0110_0000,
+    0b0111_1100,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0110_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ы'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1111_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'З'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0011_1100,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ш'
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Э'
+    0b0000_0000,
+    0b0000_0000,
+    0b0111_1100,
+    0b1100_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0011_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b1100_0110,
+    0b0111_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Щ'
+    0b0000_0000,
+    0b0000_0000,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1101_0110,
+    0b1111_1111,
+    0b0000_0001,
+    0b0000_0001,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ч'
+    0b0000_0000,
+    0b0000_0000,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b1100_0110,
+    0b0111_1110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0110,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    // 'Ъ'
+    0b0000_0000,
+    0b0000_0000,
+    0b1111_0000,
+    0b1011_0000,
+    0b0011_0000,
+    0b0011_0000,
+    0b0011_1100,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_0110,
+    0b0011_1100,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+    0b0000_0000,
+];
+
+// End of file
@@ -39,11 +39,10 @@
 //! pixels. But you can substitute your own font if required (e.g. for
 //! Teletext support).
 //!
-//! There is optional cursor support. Rather than try and check each text cell
-//! at render time to see if it is in the cursor position, we swap chars in
-//! and out of the text buffer as the cursor moves. It's a little more
-//! expensive, but the cost is at text write time, not at render time (and so
-//! it won't break sync).
+//! There is optional cursor support. The cursor is drawn over the text at
+//! render time, so the text buffer always holds what was actually written.
+//! It can be a block, an underline or a vertical bar, in its own colours,
+//! and can blink.
 //!
 //! See https://github.com/thejpster/monotron for an example.
 
@@ -59,6 +58,10 @@
 extern crate console_traits;
 #[macro_use]
 extern crate const_ft;
+#[cfg(feature = "embedded_graphics")]
+extern crate embedded_graphics_core;
+#[cfg(test)]
+extern crate std;
 
 // ***************************************************************************
 //
@@ -66,10 +69,24 @@ extern crate const_ft;
 //
 // ***************************************************************************
 
+mod canvas;
 mod charset;
+mod codepage;
+pub mod cp437;
+pub mod effects;
 pub mod freebsd_cp850;
+pub mod freebsd_cp850_bold;
 pub mod freebsd_teletext;
+#[cfg(feature = "embedded_graphics")]
+mod graphics;
+pub mod image;
+pub mod iso_8859_1;
+pub mod iso_8859_15;
+pub mod koi8_r;
+#[cfg(not(any(feature = "compact_rgb", feature = "rgbi")))]
 mod maps;
+mod ram_font;
+pub mod translit;
 
 // ***************************************************************************
 //
@@ -77,10 +94,14 @@ mod maps;
 //
 // ***************************************************************************
 
+pub use canvas::{FillIncomplete, Ink, Mode2Canvas};
 pub use charset::*;
+pub use codepage::CodePage;
 pub use console_traits::*;
 use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(any(feature = "compact_rgb", feature = "rgbi")))]
 use maps::RGB_MAPS;
+pub use ram_font::RamFont;
 
 // ***************************************************************************
 //
@@ -109,6 +130,18 @@ pub const MODE0_TEXT_MAX_ROW: usize = MODE0_TEXT_NUM_ROWS - 1;
 pub const MODE2_WIDTH_PIXELS: usize = 384;
 /// Number of scan-lines in an image in Mode 2. Note, we print each one twice.
 pub const MODE2_USABLE_LINES: usize = 288;
+/// How many times each Mode 2 line is printed, unless changed with
+/// `FrameBuffer::mode2_region_scale`.
+pub const MODE2_DEFAULT_SCALE: usize = 2;
+/// How many Mode 2 regions can be on the screen at once
+pub const MODE2_MAX_REGIONS: usize = 4;
+
+/// How many values `Colour` has (16 with the `rgbi` feature)
+#[cfg(not(feature = "rgbi"))]
+pub const NUM_COLOURS: usize = 8;
+/// How many values `Colour` has (16 with the `rgbi` feature)
+#[cfg(feature = "rgbi")]
+pub const NUM_COLOURS: usize = 16;
 
 // ***************************************************************************
 //
@@ -152,9 +185,91 @@ const V_BOTTOM_BORDER_LAST: usize = V_FRONT_PORCH_FIRST - 1;
 const V_FRONT_PORCH_FIRST: usize = V_BOTTOM_BORDER_FIRST + V_BOTTOM_BORDER;
 
 // White on Blue
-const DEFAULT_ATTR: Attr = Attr::new(Colour::White, Colour::Blue);
-
-const CURSOR: Char = Char::LowLine;
+const DEFAULT_FG: Colour = Colour::White;
+const DEFAULT_BG: Colour = Colour::Blue;
+const DEFAULT_ATTR: Attr = Attr::new(DEFAULT_FG, DEFAULT_BG);
+
+// How many scan-lines an underline cursor covers
+const CURSOR_UNDERLINE_HEIGHT: usize = 2;
+
+// The pixels a bar cursor covers (remember these are double-width)
+const CURSOR_BAR_PIXELS: u8 = 0b1100_0000;
+
+// How many numbers we keep from an `ESC [` sequence
+const CSI_MAX_PARAMS: usize = 8;
+
+/// The `Colour` for each ANSI colour number (as used by `ESC [ 30 m` etc).
+const ANSI_COLOURS: [Colour; 8] = [
+    Colour::Black,
+    Colour::Red,
+    Colour::Green,
+    Colour::Yellow,
+    Colour::Blue,
+    Colour::Magenta,
+    Colour::Cyan,
+    Colour::White,
+];
+
+/// The colours `ESC [ 90 m` to `ESC [ 97 m` (and 100 to 107) select.
+/// Without the `rgbi` feature we don't have bright colours, so these are
+/// the ordinary ones.
+#[cfg(not(feature = "rgbi"))]
+const ANSI_BRIGHT_COLOURS: [Colour; 8] = ANSI_COLOURS;
+
+/// The colours `ESC [ 90 m` to `ESC [ 97 m` (and 100 to 107) select.
+#[cfg(feature = "rgbi")]
+const ANSI_BRIGHT_COLOURS: [Colour; 8] = [
+    Colour::Grey,
+    Colour::BrightRed,
+    Colour::BrightGreen,
+    Colour::BrightYellow,
+    Colour::BrightBlue,
+    Colour::BrightMagenta,
+    Colour::BrightCyan,
+    Colour::BrightWhite,
+];
+
+/// The luminance of each `Colour`, indexed by the colour value.
+#[cfg(not(feature = "rgbi"))]
+const COLOUR_LUMINANCE: [u8; 8] = [0, 29, 150, 179, 76, 105, 226, 255];
+
+/// The luminance of each `Colour`, indexed by the colour value. Like CGA, the
+/// ordinary colours are at two-thirds brightness and intensity adds the
+/// other third.
+#[cfg(feature = "rgbi")]
+const COLOUR_LUMINANCE: [u8; 16] = [
+    0, 19, 100, 119, 51, 70, 151, 170, 85, 104, 185, 204, 136, 155, 236, 255,
+];
+
+/// Eight pixels of each `Colour`, indexed by the 3-bit colour value. Used
+/// to build RGB words without `RGB_MAPS`.
+#[cfg(all(feature = "compact_rgb", not(feature = "rgbi")))]
+const COLOUR_MASKS: [u32; 8] = [
+    0x00_00_00, 0x00_00_FF, 0x00_FF_00, 0x00_FF_FF, 0xFF_00_00, 0xFF_00_FF, 0xFF_FF_00, 0xFF_FF_FF,
+];
+
+/// Eight pixels of each `Colour`, indexed by the 4-bit colour value, with
+/// the intensity plane in the top byte. A full `RGB_MAPS` for 16 colours
+/// would be 256 KiB, so with the `rgbi` feature we always use this instead.
+#[cfg(feature = "rgbi")]
+const COLOUR_MASKS: [u32; 16] = [
+    0x00_00_00_00,
+    0x00_00_00_FF,
+    0x00_00_FF_00,
+    0x00_00_FF_FF,
+    0x00_FF_00_00,
+    0x00_FF_00_FF,
+    0x00_FF_FF_00,
+    0x00_FF_FF_FF,
+    0xFF_00_00_00,
+    0xFF_00_00_FF,
+    0xFF_00_FF_00,
+    0xFF_00_FF_FF,
+    0xFF_FF_00_00,
+    0xFF_FF_00_FF,
+    0xFF_FF_FF_00,
+    0xFF_FF_FF_FF,
+];
 
 // ***************************************************************************
 //
@@ -193,6 +308,15 @@ pub trait Hardware {
 
     /// Called word by word as pixels are calculated
     fn write_pixels(&mut self, xrgb: XRGBColour);
+
+    /// Called instead of `write_pixels` in monochrome mode (see
+    /// `FrameBuffer::set_mono_output`). Each set bit is a lit pixel, with
+    /// the most significant bit on the left. Implement this if you only
+    /// have one video pin - by default the pixels are sent to `write_pixels`
+    /// as white on black.
+    fn write_mono_pixels(&mut self, pixels: u8) {
+        self.write_pixels(XRGBColour::new(pixels, pixels, pixels));
+    }
 }
 
 // ***************************************************************************
@@ -202,6 +326,7 @@ pub trait Hardware {
 // ***************************************************************************
 
 /// Describes the parameters for a particular video mode.
+#[allow(dead_code)]
 trait VideoMode {
     /// How many octets wide is a given scan-line.
     fn octets(&self) -> usize;
@@ -250,6 +375,9 @@ where
     frame: usize,
     // Add one extra row because 600 doesn't divide by 16
     text_buffer: [Mode0TextRow; MODE0_TEXT_NUM_ROWS + 1],
+    // Which row of `text_buffer` is at the top of the screen. Scrolling
+    // just moves this on, rather than copying every row.
+    first_row: usize,
     // Allows us to map any visible line to any other visible line.
     roller_buffer: [u16; MODE0_USABLE_LINES],
     hw: Option<T>,
@@ -257,19 +385,60 @@ where
     pos: Position,
     mode: ControlCharMode,
     escape_mode: EscapeCharMode,
-    mode2: Option<Mode2>,
+    mode2: [Option<Mode2>; MODE2_MAX_REGIONS],
+    tile_mode: Option<TileMode>,
     font: Option<*const u8>,
+    alt_font: Option<*const u8>,
+    #[cfg(feature = "bold")]
+    bold_font: Option<*const u8>,
+    // Raw, as the renderer reads it through `font` while `define_glyph`
+    // writes to it
+    ram_font: Option<*mut RamFont>,
+    glyph_escape: Option<GlyphEscape>,
+    csi_escape: Option<CsiEscape>,
+    code_page: CodePage,
     cursor_visible: bool,
-    under_cursor: Char,
+    cursor_style: CursorStyle,
+    cursor_attr: Option<Attr>,
+    cursor_blink: usize,
+    scrollback: Option<Scrollback>,
+    // In monochrome mode, a bit for each `Colour` which is lit
+    mono: Option<u16>,
+    // Where `ESC [ ? 1049 h` saves the screen, and whether it has
+    alt_screen: Option<&'static mut SavedScreen>,
+    alt_screen_active: bool,
+    // With dithering, the colour to show for each `Colour`, in each phase
+    dither: Option<[[u8; NUM_COLOURS]; 2]>,
 }
 
 /// This structure describes the attributes for a Char.
-/// They're all packed into 8 bits to save RAM.
+/// They're all packed into 8 bits to save RAM (or 16 bits with the `rgbi`
+/// feature, which needs an extra bit each for the foreground and
+/// background).
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct Attr(u8);
+pub struct Attr(AttrBits);
 
+/// One of the colours a pixel can be. The `rgbi` feature adds the bright
+/// colours and grey, so a `match` on this must have a `_` arm.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum Colour {
+    #[cfg(feature = "rgbi")]
+    BrightWhite = 15,
+    #[cfg(feature = "rgbi")]
+    BrightYellow = 14,
+    #[cfg(feature = "rgbi")]
+    BrightMagenta = 13,
+    #[cfg(feature = "rgbi")]
+    BrightRed = 12,
+    #[cfg(feature = "rgbi")]
+    BrightCyan = 11,
+    #[cfg(feature = "rgbi")]
+    BrightGreen = 10,
+    #[cfg(feature = "rgbi")]
+    BrightBlue = 9,
+    #[cfg(feature = "rgbi")]
+    Grey = 8,
     White = 7,
     Yellow = 6,
     Magenta = 5,
@@ -280,15 +449,68 @@ pub enum Colour {
     Black = 0,
 }
 
-/// Represents 8 pixels, each of which can be any 3-bit RGB colour
+/// Represents 8 pixels, each of which can be any 3-bit RGB colour. With the
+/// `rgbi` feature, the top byte holds a fourth (intensity) plane.
 #[derive(Debug, Copy, Clone)]
 pub struct XRGBColour(pub u32);
 
 /// Represents Mode2 1-bpp graphics
+#[derive(Copy, Clone)]
 pub struct Mode2 {
     buffer: *const u8,
+    // Number of bytes in `buffer`
+    length: usize,
+    layout: Mode2Layout,
+    // Number of lines in `buffer`
+    lines: usize,
+    // How many scan-lines each line of `buffer` is shown on
+    scale: usize,
     start: usize,
     end: usize,
+    // Colours for this region, if not taken from the text buffer
+    attrs: Option<*const Attr>,
+    // Number of `Attr`s in `attrs`
+    attrs_len: usize,
+    // How many lines of `buffer` share each row of `attrs`
+    attr_block_lines: usize,
+    overlay: TextOverlay,
+}
+
+/// The order of the pixels in each byte of a Mode 2 buffer.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum BitOrder {
+    /// The most significant bit is the left-most pixel (the default).
+    #[default]
+    MsbFirst,
+    /// The least significant bit is the left-most pixel, as in XBM files
+    /// and most Linux framebuffers.
+    LsbFirst,
+}
+
+/// How the pixels are arranged in a Mode 2 buffer. See
+/// `FrameBuffer::mode2_region_layout`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Mode2Layout {
+    /// The order of the pixels in each byte.
+    pub bit_order: BitOrder,
+    /// The number of bytes from the start of one line to the start of the
+    /// next.
+    pub stride: usize,
+    /// The number of pixels to skip at the start of each line.
+    pub offset: usize,
+}
+
+/// How the text in a Mode 2 region is shown.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum TextOverlay {
+    /// Only the graphics are shown (the default).
+    #[default]
+    Hidden,
+    /// Text pixels are ORed with the graphics.
+    Or,
+    /// Text pixels are XORed with the graphics, so text is visible on both
+    /// set and clear pixels.
+    Xor,
 }
 
 /// A point on the screen.
@@ -305,19 +527,139 @@ pub enum DoubleHeightMode {
     Bottom,
 }
 
+/// Which of the two fonts a row is drawn in. See
+/// `FrameBuffer::set_alt_font`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FontSlot {
+    /// The current font (see `set_custom_font`).
+    Main,
+    /// The alternate font - the Teletext font, unless you've changed it.
+    Alt,
+}
+
+/// The shapes the text cursor can take.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum CursorStyle {
+    /// Inverts the whole character cell.
+    Block,
+    /// A line across the bottom of the character cell.
+    #[default]
+    Underline,
+    /// A line down the left-hand side of the character cell.
+    Bar,
+}
+
+/// One cell of a tile map - which tile to draw, and in which colours.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Tile {
+    pub index: u8,
+    pub attr: Attr,
+}
+
 #[derive(Copy, Clone)]
 pub struct Mode0TextRow {
     pub double_height: DoubleHeightMode,
+    font: FontSlot,
     pub glyphs: [(Char, Attr); MODE0_TEXT_NUM_COLS],
 }
 
+/// Everything on the screen, saved by `FrameBuffer::save_screen` so it can
+/// be put back later. This is 3,532 bytes (6,990 with the `rgbi` feature),
+/// so think about where you put it on a small microcontroller.
+#[derive(Clone)]
+pub struct SavedScreen {
+    rows: [Mode0TextRow; MODE0_TEXT_NUM_ROWS],
+    pos: Position,
+    attr: Attr,
+    cursor_visible: bool,
+}
+
 // ***************************************************************************
 //
 // Private Types
 //
 // ***************************************************************************
 
-// None
+/// How to turn a scan-line into pixels. `calculate_pixels` picks this from
+/// the `FrameBuffer` settings once per line, so that each octet only does
+/// the work for the mode in use.
+#[derive(Copy, Clone)]
+enum Output {
+    /// Colour pixels, in the colours in each `Attr`
+    Colour,
+    /// Colour pixels, with each `Colour` swapped for the one to show on this
+    /// line
+    Dither([u8; NUM_COLOURS]),
+    /// Monochrome pixels, with a bit for each `Colour` which is lit. The
+    /// colours are swapped first if we're also dithering.
+    Mono(u16, Option<[u8; NUM_COLOURS]>),
+}
+
+/// The fonts a text row is drawn from. The bold font is only looked at with
+/// the `bold` feature.
+#[derive(Copy, Clone)]
+struct FontTables {
+    regular: *const u8,
+    #[cfg(feature = "bold")]
+    bold: *const u8,
+}
+
+/// The bits in an `Attr`.
+#[cfg(not(feature = "rgbi"))]
+type AttrBits = u8;
+
+/// The bits in an `Attr`.
+#[cfg(feature = "rgbi")]
+type AttrBits = u16;
+
+/// Represents the tile-map graphics mode.
+struct TileMode {
+    // 256 tiles, `tile_height` bytes each
+    tiles: *const u8,
+    tile_height: usize,
+    // `map_width` x `map_height` tiles, a row at a time
+    map: *const Tile,
+    map_width: usize,
+    map_height: usize,
+    // Position of the top left of the screen in the map, in pixels
+    scroll_x: usize,
+    scroll_y: usize,
+    // The scan-lines the tiles are shown on
+    start: usize,
+    end: usize,
+}
+
+/// A partly received `ESC =` (define glyph) sequence.
+#[derive(Copy, Clone)]
+struct GlyphEscape {
+    // How many hex digits we've had so far
+    digits: usize,
+    ch: u8,
+    rows: [u8; MAX_FONT_HEIGHT],
+}
+
+/// A partly received `ESC [` (Control Sequence Introducer) sequence.
+#[derive(Copy, Clone)]
+struct CsiEscape {
+    params: [u16; CSI_MAX_PARAMS],
+    // How many numbers we've started (including empty ones)
+    count: usize,
+    // Started with `?`
+    private: bool,
+}
+
+/// Rows which have scrolled off the top of the screen, kept in a ring
+/// buffer supplied by the caller.
+struct Scrollback {
+    rows: &'static mut [Mode0TextRow],
+    /// Where the next row will be stored (which is the oldest row, once the
+    /// buffer is full)
+    next: usize,
+    /// How many rows have been stored
+    len: usize,
+    /// How many rows back we're looking. 0 shows the live screen.
+    offset: usize,
+}
 
 // ***************************************************************************
 //
@@ -329,7 +671,7 @@ impl<T> FrameBuffer<T>
 where
     T: Hardware,
 {
-    /// Create a new FrameBuffer.
+    // Create a new FrameBuffer.
     const_ft! {
         // We can't use `pub const` as const-fn isn't supported with generics.
         pub fn new() -> FrameBuffer<T> {
@@ -338,8 +680,10 @@ where
                 frame: 0,
                 text_buffer: [Mode0TextRow {
                     double_height: DoubleHeightMode::Normal,
+                    font: FontSlot::Main,
                     glyphs: [(Char::Null, DEFAULT_ATTR); MODE0_TEXT_NUM_COLS],
                 }; MODE0_TEXT_NUM_ROWS + 1],
+                first_row: 0,
                 roller_buffer: [0; MODE0_USABLE_LINES],
                 hw: None,
                 pos: Position {
@@ -349,10 +693,25 @@ where
                 attr: DEFAULT_ATTR,
                 mode: ControlCharMode::Interpret,
                 escape_mode: EscapeCharMode::Waiting,
-                mode2: None,
+                mode2: [None; MODE2_MAX_REGIONS],
+                tile_mode: None,
                 font: None,
+                alt_font: None,
+                #[cfg(feature = "bold")]
+                bold_font: None,
+                ram_font: None,
+                glyph_escape: None,
+                csi_escape: None,
+                code_page: CodePage::Cp850,
                 cursor_visible: true,
-                under_cursor: Char::Space,
+                cursor_style: CursorStyle::Underline,
+                cursor_attr: None,
+                cursor_blink: 0,
+                scrollback: None,
+                mono: None,
+                alt_screen: None,
+                alt_screen_active: false,
+                dither: None,
             }
         }
     }
@@ -362,21 +721,21 @@ where
         // This all assumes an 8-pixel font (i.e. 1 byte or two per u16)
         assert_eq!(MAX_FONT_WIDTH, 8);
         let mode_info = ModeInfo {
-            /// Number of pixels in a line (including blanking)
+            // Number of pixels in a line (including blanking)
             width: H_WHOLE_LINE_20MHZ,
-            /// Number of pixels in a line (excluding blanking)
+            // Number of pixels in a line (excluding blanking)
             visible_width: H_VISIBLE_AREA_20MHZ,
-            /// Elapsed time (in `clock_rate` pixels) before H-Sync needs to
-            /// fall
+            // Elapsed time (in `clock_rate` pixels) before H-Sync needs to
+            // fall
             sync_end: H_SYNC_PULSE_20MHZ,
-            /// Elapsed time (in `clock_rate` pixels) before line_start ISR
-            /// needs to fire
+            // Elapsed time (in `clock_rate` pixels) before line_start ISR
+            // needs to fire
             line_start: H_SYNC_PULSE_20MHZ + H_BACK_PORCH_20MHZ,
-            /// The pixel clock rate in Hz (e.g. 40_000_000 for 40 MHz)
+            // The pixel clock rate in Hz (e.g. 40_000_000 for 40 MHz)
             clock_rate: 20_000_000,
-            /// Number of lines on the screen (including blanking)
+            // Number of lines on the screen (including blanking)
             num_lines: V_WHOLE_FRAME as u32,
-            /// Number of lines on the screen (excluding blanking)
+            // Number of lines on the screen (excluding blanking)
             visible_lines: V_VISIBLE_AREA as u32,
         };
 
@@ -405,47 +764,263 @@ where
     }
 
     pub fn set_cursor_visible(&mut self, visible: bool) {
-        if visible != self.cursor_visible {
-            if visible {
-                self.cursor_visible = true;
-                self.under_cursor = self.current_cell().0;
-                self.current_cell().0 = CURSOR;
-            } else {
-                self.cursor_visible = false;
-                self.current_cell().0 = self.under_cursor;
-            }
-        }
+        self.cursor_visible = visible;
+    }
+
+    /// Is the cursor currently shown? This ignores blinking.
+    pub fn get_cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Change the shape of the cursor.
+    pub fn set_cursor_style(&mut self, style: CursorStyle) {
+        self.cursor_style = style;
+    }
+
+    /// Get the shape of the cursor.
+    pub fn get_cursor_style(&self) -> CursorStyle {
+        self.cursor_style
+    }
+
+    /// Set the colours for the character cell under the cursor. `None`
+    /// (the default) uses the cell's own colours.
+    pub fn set_cursor_attr(&mut self, attr: Option<Attr>) {
+        self.cursor_attr = attr;
+    }
+
+    /// Make the cursor blink, showing it for `frames` frames and then hiding
+    /// it for `frames` frames (e.g. 16 gives about two blinks per second). 0
+    /// (the default) stops it blinking.
+    pub fn set_cursor_blink(&mut self, frames: usize) {
+        self.cursor_blink = frames;
     }
 
     /// Enable mode2 - a 1-bit-per-pixel graphical buffer which is coloured
     /// according to the colour attributes for the matching text cells.
     /// Supply a u8 slice that is some multiple of MODE0_USABLE_HORIZONTAL_OCTETS long.
-    /// The buffer will be line-doubled and so can be up to 288 lines long.
+    /// The buffer will be line-doubled and so can be up to 288 lines long
+    /// (but see `mode2_region_scale`).
+    ///
+    /// This uses the first Mode 2 region - see `mode2_region` if you want
+    /// more than one.
     pub fn mode2(&mut self, buffer: &[u8], start_line: usize) {
+        self.mode2_region(0, buffer, start_line);
+    }
+
+    /// Move the first Mode 2 region so it starts on a new scan-line.
+    pub fn mode2_shift(&mut self, new_start_line: usize) {
+        self.mode2_region_shift(0, new_start_line);
+    }
+
+    /// Change how the pixels are arranged in the first Mode 2 region's
+    /// buffer. See `mode2_region_layout`.
+    pub fn mode2_layout(&mut self, layout: Mode2Layout) {
+        self.mode2_region_layout(0, layout);
+    }
+
+    /// Change how many scan-lines each line of the first Mode 2 region is
+    /// shown on. See `mode2_region_scale`.
+    pub fn mode2_scale(&mut self, scale: usize) {
+        self.mode2_region_scale(0, scale);
+    }
+
+    /// Releases the memory for every Mode 2 region. The rendering code may
+    /// keep reading these memory buffers up until the end of the frame.
+    pub fn mode2_release(&mut self) {
+        for region in self.mode2.iter_mut() {
+            *region = None;
+        }
+    }
+
+    /// Enable one of the `MODE2_MAX_REGIONS` Mode 2 regions, as per `mode2`.
+    /// Each region has its own buffer, so you can have graphics in several
+    /// bands of the screen with text in between, without needing a buffer
+    /// for the whole screen. Where regions overlap, the one with the lowest
+    /// index is shown.
+    ///
+    /// Panics if `index` isn't less than `MODE2_MAX_REGIONS`.
+    pub fn mode2_region(&mut self, index: usize, buffer: &[u8], start_line: usize) {
         let length = buffer.len();
         let buffer_lines = length / MODE0_USABLE_HORIZONTAL_OCTETS;
         let mode2 = Mode2 {
             buffer: buffer.as_ptr(),
+            length,
+            layout: Mode2Layout::default(),
+            lines: buffer_lines,
+            scale: MODE2_DEFAULT_SCALE,
             start: start_line,
-            // Framebuffer is line-doubled
-            end: start_line + (2 * buffer_lines),
+            end: start_line + (MODE2_DEFAULT_SCALE * buffer_lines),
+            attrs: None,
+            attrs_len: 0,
+            attr_block_lines: 1,
+            overlay: TextOverlay::Hidden,
         };
-        self.mode2 = Some(mode2);
+        self.mode2[index] = Some(mode2);
     }
 
-    pub fn mode2_shift(&mut self, new_start_line: usize) {
-        if let Some(mode2) = self.mode2.as_mut() {
+    /// Colour a Mode 2 region from its own attributes, rather than from the
+    /// text cells underneath it. This avoids clashing with the text, and
+    /// gives finer control, like a ZX Spectrum (8x8 blocks) or MSX (8x1).
+    ///
+    /// There is one `Attr` for each 8 pixel wide, `block_lines` high block
+    /// of the region's buffer, stored a row at a time (so
+    /// `MODE0_USABLE_HORIZONTAL_OCTETS` per row). Remember the buffer is
+    /// scaled (normally line-doubled), so a block is `2 * block_lines`
+    /// scan-lines high on screen. The region must already be enabled with
+    /// `mode2_region`.
+    ///
+    /// Panics if `attrs` is too short for the region, or `block_lines` is 0.
+    pub fn mode2_region_attrs(&mut self, index: usize, attrs: &[Attr], block_lines: usize) {
+        assert!(block_lines > 0);
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            let attr_rows = mode2.lines.div_ceil(block_lines);
+            assert!(attrs.len() >= attr_rows * MODE0_USABLE_HORIZONTAL_OCTETS);
+            mode2.attrs = Some(attrs.as_ptr());
+            mode2.attrs_len = attrs.len();
+            mode2.attr_block_lines = block_lines;
+        }
+    }
+
+    /// Show the text over a Mode 2 region, combining the glyph pixels with
+    /// the graphics. Each character cell takes its colours from the text
+    /// buffer, unless its `Attr` is transparent (see `Attr::set_transparent`)
+    /// in which case it takes the graphics' colours. Spaces don't change any
+    /// pixels, so a transparent space leaves the graphics untouched.
+    pub fn mode2_region_overlay(&mut self, index: usize, overlay: TextOverlay) {
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            mode2.overlay = overlay;
+        }
+    }
+
+    /// Go back to colouring a Mode 2 region from the text cells underneath
+    /// it. The rendering code may keep reading the old attributes up until
+    /// the end of the frame.
+    pub fn mode2_region_attrs_release(&mut self, index: usize) {
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            mode2.attrs = None;
+        }
+    }
+
+    /// Move a Mode 2 region so it starts on a new scan-line.
+    pub fn mode2_region_shift(&mut self, index: usize, new_start_line: usize) {
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            mode2.end = new_start_line + (mode2.end - mode2.start);
             mode2.start = new_start_line;
         }
     }
 
-    /// Releases the memory for mode2. The rendering code may keep
+    /// Change how many scan-lines each line of a Mode 2 region is shown on.
+    /// The default is `MODE2_DEFAULT_SCALE` (2). A scale of 1 gives the full
+    /// 576 line vertical resolution, but needs twice the memory for the same
+    /// height on screen; 3 or 4 gives chunkier pixels from less memory. The
+    /// region keeps its start line, and its end moves to match.
+    ///
+    /// Panics if `scale` is 0.
+    pub fn mode2_region_scale(&mut self, index: usize, scale: usize) {
+        assert!(scale > 0);
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            mode2.scale = scale;
+            mode2.end = mode2.start + (scale * mode2.lines);
+        }
+    }
+
+    /// Change how the pixels are arranged in a Mode 2 region's buffer, so
+    /// you can show images in other formats without copying them. For
+    /// example, an XBM image is `BitOrder::LsbFirst`, and to show the right
+    /// hand part of a 640 pixel wide image use a `stride` of 80 and an
+    /// `offset` of 256. The region keeps its start line, and its end moves to
+    /// match the number of whole lines now in the buffer. If the region has
+    /// its own attributes (see `mode2_region_attrs`) and there are now too
+    /// few of them for that many lines, it goes back to colouring from the
+    /// text cells.
+    ///
+    /// Panics if `layout.stride` is less than the number of bytes read from
+    /// each line, which is `layout.offset + MODE2_WIDTH_PIXELS` bits rounded
+    /// up to whole bytes.
+    pub fn mode2_region_layout(&mut self, index: usize, layout: Mode2Layout) {
+        // The bytes we read from each line, including one more at the end if
+        // the offset isn't a whole number of bytes
+        let line_length = layout.offset.saturating_add(MODE2_WIDTH_PIXELS).div_ceil(8);
+        assert!(layout.stride >= line_length);
+        if let Some(mode2) = self.mode2[index].as_mut() {
+            mode2.lines = if mode2.length >= line_length {
+                ((mode2.length - line_length) / layout.stride) + 1
+            } else {
+                0
+            };
+            mode2.layout = layout;
+            mode2.end = mode2.start + (mode2.scale * mode2.lines);
+            let attr_rows = mode2.lines.div_ceil(mode2.attr_block_lines);
+            if mode2.attrs_len < attr_rows * MODE0_USABLE_HORIZONTAL_OCTETS {
+                mode2.attrs = None;
+            }
+        }
+    }
+
+    /// Releases the memory for a Mode 2 region. The rendering code may keep
     /// reading this memory buffer up until the end of the frame.
-    pub fn mode2_release(&mut self) {
-        let mut mode2_opt = None;
-        core::mem::swap(&mut self.mode2, &mut mode2_opt);
+    pub fn mode2_region_release(&mut self, index: usize) {
+        self.mode2[index] = None;
+    }
+
+    /// Enable tile mode - the screen shows a grid of 8 pixel wide tiles,
+    /// like a font you can fill with anything, which is cheaper on RAM than
+    /// Mode 2. Each cell in the map picks a tile and has its own colours.
+    ///
+    /// `tiles` holds 256 tiles of `tile_height` bytes each (typically 8 or
+    /// 16), with the most significant bit of each byte on the left. `map`
+    /// is `map_width` tiles wide and as many rows high as it holds. The map
+    /// can be bigger than the screen - use `tile_mode_scroll` to move around
+    /// it. It wraps around at the edges.
+    ///
+    /// Tiles cover the whole screen, apart from any Mode 2 regions, unless
+    /// you change that with `tile_mode_lines`.
+    ///
+    /// Panics if `tiles` isn't `256 * tile_height` bytes long, or `map` isn't
+    /// a non-zero whole number of rows.
+    pub fn tile_mode(&mut self, tiles: &[u8], tile_height: usize, map: &[Tile], map_width: usize) {
+        assert!(tile_height > 0);
+        assert_eq!(tiles.len(), 256 * tile_height);
+        assert!(map_width > 0 && !map.is_empty() && map.len().is_multiple_of(map_width));
+        self.tile_mode = Some(TileMode {
+            tiles: tiles.as_ptr(),
+            tile_height,
+            map: map.as_ptr(),
+            map_width,
+            map_height: map.len() / map_width,
+            scroll_x: 0,
+            scroll_y: 0,
+            start: 0,
+            end: MODE0_USABLE_LINES,
+        });
+    }
+
+    /// Only show tiles on scan-lines `start_line..end_line`, leaving text
+    /// on the rest of the screen (e.g. for a status bar).
+    pub fn tile_mode_lines(&mut self, start_line: usize, end_line: usize) {
+        if let Some(tile_mode) = self.tile_mode.as_mut() {
+            tile_mode.start = start_line;
+            tile_mode.end = end_line;
+        }
+    }
+
+    /// Set the position in the tile map, in pixels, which appears at the
+    /// top left of the tiled area.
+    pub fn tile_mode_scroll(&mut self, x: usize, y: usize) {
+        if let Some(tile_mode) = self.tile_mode.as_mut() {
+            tile_mode.scroll_x = x % (tile_mode.map_width * MAX_FONT_WIDTH);
+            tile_mode.scroll_y = y % (tile_mode.map_height * tile_mode.tile_height);
+        }
+    }
+
+    /// Go back to showing text. The rendering code may keep reading the tiles
+    /// and map up until the end of the frame.
+    pub fn tile_mode_release(&mut self) {
+        self.tile_mode = None;
     }
 
+    /// Make a visible scan-line show a different rendered scan-line. See the
+    /// `effects` module for some ways to use this.
     pub fn map_line(&mut self, visible_line: u16, rendered_line: u16) {
         if (rendered_line as usize) < MODE0_USABLE_LINES {
             if let Some(n) = self.roller_buffer.get_mut(visible_line as usize) {
@@ -462,7 +1037,7 @@ where
     /// Returns the current visible line number or None in the blanking period.
     pub fn line(&self) -> Option<usize> {
         let line = self.line_no.load(Ordering::Relaxed);
-        if line >= V_DATA_FIRST && line <= V_DATA_LAST {
+        if (V_DATA_FIRST..=V_DATA_LAST).contains(&line) {
             Some(line - V_DATA_FIRST)
         } else {
             None
@@ -522,7 +1097,7 @@ where
         if let Some(ref mut hw) = self.hw {
             // Middle bit
             for _ in 0..MODE0_HORIZONTAL_OCTETS {
-                hw.write_pixels(XRGBColour::new(0xFF, 0xFF, 0xFF));
+                write_border(hw, self.mono);
             }
         }
     }
@@ -533,93 +1108,431 @@ where
     /// triplets to the callback function (to be buffered).
     fn calculate_pixels(&mut self) {
         let real_line = self.line_no.load(Ordering::Relaxed) - V_DATA_FIRST;
-        let line = self.roller_buffer[real_line as usize] as usize;
+        let line = self.roller_buffer[real_line] as usize;
         let text_row = line / MAX_FONT_HEIGHT;
-        let row = &self.text_buffer[text_row];
+        let row = match self.scrollback {
+            Some(ref scrollback) if text_row < scrollback.offset => {
+                scrollback.row(scrollback.offset - text_row)
+            }
+            Some(ref scrollback) => {
+                &self.text_buffer[self.buffer_row(text_row - scrollback.offset)]
+            }
+            None => &self.text_buffer[self.buffer_row(text_row)],
+        };
         let font_row = match row.double_height {
             DoubleHeightMode::Normal => line % MAX_FONT_HEIGHT,
             DoubleHeightMode::Top => (line % MAX_FONT_HEIGHT) / 2,
             DoubleHeightMode::Bottom => ((line % MAX_FONT_HEIGHT) + MAX_FONT_HEIGHT) / 2,
         };
-        let font_table = self
-            .font
-            .unwrap_or_else(|| freebsd_cp850::FONT_DATA.as_ptr());
+        let fonts = match row.font {
+            FontSlot::Main => {
+                let regular = self.font.unwrap_or(freebsd_cp850::FONT_DATA.as_ptr());
+                FontTables {
+                    regular,
+                    #[cfg(feature = "bold")]
+                    bold: self.bold_font.unwrap_or(match self.font {
+                        None => freebsd_cp850_bold::FONT_DATA.as_ptr(),
+                        Some(_) => regular,
+                    }),
+                }
+            }
+            FontSlot::Alt => {
+                let regular = self
+                    .alt_font
+                    .unwrap_or(freebsd_teletext::FONT_DATA.as_ptr());
+                FontTables {
+                    regular,
+                    #[cfg(feature = "bold")]
+                    bold: regular,
+                }
+            }
+        };
+        let (cursor_col, cursor_or, cursor_xor) =
+            self.cursor_pixels(text_row, line % MAX_FONT_HEIGHT);
+        let cursor_attr = self.cursor_attr;
+        // Alternate lines are dithered in opposite phases, so the screen as
+        // a whole doesn't flicker so much. The frame counter wraps, so XOR
+        // rather than add.
+        let dither = self.dither.map(|d| d[(self.frame ^ real_line) & 1]);
+        let mono = self.mono;
+        let output = match (mono, dither) {
+            (None, None) => Output::Colour,
+            (None, Some(colours)) => Output::Dither(colours),
+            (Some(lit), dither) => Output::Mono(lit, dither),
+        };
         if let Some(ref mut hw) = self.hw {
             // Left border
-            hw.write_pixels(XRGBColour::new(0xFF, 0xFF, 0xFF));
+            write_border(hw, mono);
 
+            let fonts = fonts.add(font_row);
             let mut need_text = true;
-            if let Some(mode2) = self.mode2.as_ref() {
-                if line >= mode2.start && line < mode2.end && text_row < MODE0_TEXT_NUM_ROWS {
+            let mode2 = self
+                .mode2
+                .iter()
+                .flatten()
+                .find(|mode2| line >= mode2.start && line < mode2.end);
+            if let Some(mode2) = mode2 {
+                if text_row < MODE0_TEXT_NUM_ROWS {
                     // Pixels in the middle
 
-                    // Our framebuffer is line-doubled
-                    let framebuffer_line = (line - mode2.start) >> 1;
+                    // Each line of our framebuffer is shown `scale` times
+                    let framebuffer_line = (line - mode2.start) / mode2.scale;
 
-                    // Find the block of bytes for this scan-line
-                    let start = framebuffer_line * MODE0_USABLE_HORIZONTAL_OCTETS;
+                    // Find the block of bytes for this scan-line. If the
+                    // layout's offset isn't a whole number of bytes, each
+                    // octet straddles two of them.
+                    let layout = mode2.layout;
+                    let start = (framebuffer_line * layout.stride) + (layout.offset / 8);
+                    let fine = layout.offset % 8;
                     let framebuffer_offsets = (start as isize)
                         ..(start as isize + MODE0_USABLE_HORIZONTAL_OCTETS as isize);
 
-                    // Write out the bytes with colour from the text-buffer
-                    for ((_, attr), framebuffer_offset) in self.text_buffer[text_row]
-                        .glyphs
-                        .iter()
-                        .zip(framebuffer_offsets)
-                    {
-                        let w = unsafe { *mode2.buffer.offset(framebuffer_offset) };
-                        // RGB_MAPs is a lookup of (pixels, fg, bg) -> (r,g,b)
-                        // Each row is 4 bytes. The row index is
-                        // 0bFFFBBBPPPPPPPP, where F = foreground, B =
-                        // background, P = 8-bit pixels.
-                        let rgb_addr = unsafe {
-                            RGB_MAPS
-                                .as_ptr()
-                                .offset(((attr.0 as isize) * 256_isize) + (w as isize))
-                        };
-                        let rgb_word = unsafe { *rgb_addr };
-                        hw.write_pixels(rgb_word);
-                    }
+                    // Find the region's own attributes for this scan-line,
+                    // if it has them
+                    let attrs = mode2.attrs.map(|attrs| {
+                        let attr_row = framebuffer_line / mode2.attr_block_lines;
+                        unsafe { attrs.add(attr_row * MODE0_USABLE_HORIZONTAL_OCTETS) }
+                    });
+
+                    let octets = row.glyphs.iter().zip(framebuffer_offsets).enumerate().map(
+                        |(col, ((ch, text_attr), framebuffer_offset))| {
+                            let mut w =
+                                mode2_octet(mode2.buffer, framebuffer_offset, layout.bit_order);
+                            if fine != 0 {
+                                let next = mode2_octet(
+                                    mode2.buffer,
+                                    framebuffer_offset + 1,
+                                    layout.bit_order,
+                                );
+                                w = (w << fine) | (next >> (8 - fine));
+                            }
+                            let mut attr = match attrs {
+                                Some(attrs) => unsafe { *attrs.add(col) },
+                                None => *text_attr,
+                            };
+                            if mode2.overlay != TextOverlay::Hidden {
+                                // Combine the text with the graphics
+                                let glyph = glyph_row(fonts, *ch, *text_attr);
+                                w = match mode2.overlay {
+                                    TextOverlay::Xor => w ^ glyph,
+                                    _ => w | glyph,
+                                };
+                                if !text_attr.is_transparent() {
+                                    attr = *text_attr;
+                                }
+                            }
+                            (attr, w)
+                        },
+                    );
+                    write_octets(hw, &output, octets);
                     need_text = false;
                 }
             }
 
             if need_text {
-                // Characters in the middle
-                let font_table = unsafe { font_table.add(font_row) };
-                for (ch, attr) in row.glyphs.iter() {
-                    let index = (*ch as isize) * (MAX_FONT_HEIGHT as isize);
-                    let mono_pixels = unsafe { *font_table.offset(index) };
-                    // RGB_MAPs is a lookup of (pixels, fg, bg) -> (r,g,b)
-                    // Each row is 4 bytes. The row index is
-                    // 0bFFFBBBPPPPPPPP, where F = foreground, B =
-                    // background, P = 8-bit pixels.
-                    let rgb_addr = unsafe {
-                        RGB_MAPS
-                            .as_ptr()
-                            .offset(((attr.0 as isize) * 256_isize) + (mono_pixels as isize))
-                    };
-                    let rgb_word = unsafe { *rgb_addr };
-                    hw.write_pixels(rgb_word);
+                if let Some(ref tile_mode) = self.tile_mode {
+                    if line >= tile_mode.start && line < tile_mode.end {
+                        tile_mode.render_line(line, hw, &output);
+                        need_text = false;
+                    }
+                }
+            }
+
+            if need_text {
+                // Characters in the middle. The cursor's cell is done on its
+                // own, so the others needn't check for it.
+                let text = |&(ch, attr): &(Char, Attr)| (attr, glyph_row(fonts, ch, attr));
+                let (before, after) = row.glyphs.split_at(cursor_col.min(row.glyphs.len()));
+                write_octets(hw, &output, before.iter().map(text));
+                if let Some((&(ch, attr), after)) = after.split_first() {
+                    let mono_pixels = (glyph_row(fonts, ch, attr) | cursor_or) ^ cursor_xor;
+                    let cursor = (cursor_attr.unwrap_or(attr), mono_pixels);
+                    write_octets(hw, &output, core::iter::once(cursor));
+                    write_octets(hw, &output, after.iter().map(text));
                 }
             }
 
             // Right border
-            hw.write_pixels(XRGBColour::new(0xFF, 0xFF, 0xFF));
+            write_border(hw, mono);
+        }
+    }
+
+    /// Convert a row on the screen to a row in `text_buffer`.
+    fn buffer_row(&self, row: usize) -> usize {
+        let row = self.first_row + row;
+        if row >= MODE0_TEXT_NUM_ROWS {
+            row - MODE0_TEXT_NUM_ROWS
+        } else {
+            row
+        }
+    }
+
+    /// Work out where the cursor is on the given scan-line of the given text
+    /// row. Returns the column (or `usize::MAX` if there's no cursor on this
+    /// scan-line), then the pixels to OR and to XOR with that cell's glyph.
+    fn cursor_pixels(&self, text_row: usize, cell_line: usize) -> (usize, u8, u8) {
+        const NO_CURSOR: (usize, u8, u8) = (usize::MAX, 0, 0);
+        // When looking back through history, the live screen moves down
+        let offset = self.scrollback.as_ref().map_or(0, |s| s.offset);
+        if !self.cursor_visible || text_row != self.pos.row.0 as usize + offset {
+            return NO_CURSOR;
+        }
+        if self.cursor_blink != 0 && (self.frame / self.cursor_blink) & 1 != 0 {
+            return NO_CURSOR;
+        }
+        let col = self.pos.col.0 as usize;
+        match self.cursor_style {
+            CursorStyle::Block => (col, 0x00, 0xFF),
+            CursorStyle::Underline if cell_line >= MAX_FONT_HEIGHT - CURSOR_UNDERLINE_HEIGHT => {
+                (col, 0xFF, 0x00)
+            }
+            CursorStyle::Underline => NO_CURSOR,
+            CursorStyle::Bar => (col, CURSOR_BAR_PIXELS, 0x00),
+        }
+    }
+
+    /// Copy the text (with its colours and line modes), the cursor position
+    /// and visibility, and the current `Attr` into `saved`.
+    pub fn save_screen(&self, saved: &mut SavedScreen) {
+        for (index, row) in saved.rows.iter_mut().enumerate() {
+            *row = self.text_buffer[self.buffer_row(index)];
+        }
+        saved.pos = self.pos;
+        saved.attr = self.attr;
+        saved.cursor_visible = self.cursor_visible;
+    }
+
+    /// Put back everything `save_screen` saved.
+    pub fn restore_screen(&mut self, saved: &SavedScreen) {
+        self.first_row = 0;
+        self.text_buffer[..MODE0_TEXT_NUM_ROWS].copy_from_slice(&saved.rows);
+        self.pos = saved.pos;
+        self.attr = saved.attr;
+        self.cursor_visible = saved.cursor_visible;
+    }
+
+    /// Give the console somewhere to save the screen when a program asks
+    /// for the alternate screen with `ESC [ ? 1049 h`, as full-screen
+    /// programs do on xterm. `ESC [ ? 1049 l` puts the screen back. Without
+    /// it, these sequences are ignored. Returns the previous buffer, if any.
+    ///
+    /// Rows don't go into the scroll-back buffer while the alternate
+    /// screen is in use. If it is in use now, the saved screen is copied to
+    /// the new buffer, or put back on the screen if there isn't one.
+    pub fn set_alt_screen(
+        &mut self,
+        mut saved: Option<&'static mut SavedScreen>,
+    ) -> Option<&'static mut SavedScreen> {
+        if self.alt_screen_active {
+            match (saved.as_mut(), self.alt_screen.as_ref()) {
+                (Some(new), Some(old)) => (**new).clone_from(*old),
+                _ => self.leave_alt_screen(),
+            }
+        }
+        ::core::mem::replace(&mut self.alt_screen, saved)
+    }
+
+    /// Keep rows which scroll off the top of the screen in the given buffer
+    /// (oldest rows are discarded once it is full), so they can be viewed
+    /// again with `scrollback_up`. `None` stops keeping them. Returns the
+    /// previous buffer, if any.
+    pub fn set_scrollback(
+        &mut self,
+        buffer: Option<&'static mut [Mode0TextRow]>,
+    ) -> Option<&'static mut [Mode0TextRow]> {
+        let old = self.scrollback.take().map(|s| s.rows);
+        self.scrollback = buffer.map(|rows| Scrollback {
+            rows,
+            next: 0,
+            len: 0,
+            offset: 0,
+        });
+        old
+    }
+
+    /// How many rows are held in the scroll-back buffer.
+    pub fn get_scrollback_len(&self) -> usize {
+        self.scrollback.as_ref().map_or(0, |s| s.len)
+    }
+
+    /// How many rows back through the scroll-back buffer we are looking. 0
+    /// means the live screen is shown.
+    pub fn get_scrollback_offset(&self) -> usize {
+        self.scrollback.as_ref().map_or(0, |s| s.offset)
+    }
+
+    /// Look further back through the scroll-back buffer. Text can still be
+    /// written while doing this - it goes to the live screen, which is shown
+    /// again by `scrollback_reset`.
+    pub fn scrollback_up(&mut self, rows: usize) {
+        if let Some(s) = self.scrollback.as_mut() {
+            s.offset = core::cmp::min(s.offset + rows, s.len);
+        }
+    }
+
+    /// Look less far back through the scroll-back buffer.
+    pub fn scrollback_down(&mut self, rows: usize) {
+        if let Some(s) = self.scrollback.as_mut() {
+            s.offset = s.offset.saturating_sub(rows);
         }
     }
 
-    /// Change the current font
+    /// Look one screen further back through the scroll-back buffer.
+    pub fn scrollback_page_up(&mut self) {
+        self.scrollback_up(MODE0_TEXT_NUM_ROWS);
+    }
+
+    /// Look one screen less far back through the scroll-back buffer.
+    pub fn scrollback_page_down(&mut self) {
+        self.scrollback_down(MODE0_TEXT_NUM_ROWS);
+    }
+
+    /// Go back to showing the live screen.
+    pub fn scrollback_reset(&mut self) {
+        if let Some(s) = self.scrollback.as_mut() {
+            s.offset = 0;
+        }
+    }
+
+    /// Change the current font. `None` selects the default (Code Page 850)
+    /// font, whatever the code page.
     pub fn set_custom_font(&mut self, new_font: Option<&'static [u8]>) {
-        self.font = match new_font {
-            // The given font
-            Some(x) => {
-                assert_eq!(x.len(), 256 * MAX_FONT_HEIGHT);
-                Some(x.as_ptr())
-            }
-            // The default font
-            None => None,
-        };
+        self.font = new_font.map(|x| {
+            assert_eq!(x.len(), 256 * MAX_FONT_HEIGHT);
+            x.as_ptr()
+        });
+    }
+
+    /// Change the alternate font, which rows set to `FontSlot::Alt` are
+    /// drawn in. `None` selects the bundled Teletext font, so you can have
+    /// mosaic graphics on some rows and ordinary text on the others.
+    ///
+    /// Text is still converted using the current code page, so you'll
+    /// usually want to write to these rows with `AsciiConsole` rather than
+    /// `core::fmt::Write`.
+    pub fn set_alt_font(&mut self, new_font: Option<&'static [u8]>) {
+        self.alt_font = new_font.map(|x| {
+            assert_eq!(x.len(), 256 * MAX_FONT_HEIGHT);
+            x.as_ptr()
+        });
+    }
+
+    /// Change the font bold text is drawn in, on rows drawn in the current
+    /// font. `None` selects `freebsd_cp850_bold` while the current font is
+    /// the default, and otherwise draws bold text in the current font. A
+    /// bold version of any font can be made with `font-tool bold`.
+    ///
+    /// Only available with the `bold` feature, as picking the font costs a
+    /// little time for every character; without it, bold text is drawn like
+    /// any other.
+    #[cfg(feature = "bold")]
+    pub fn set_bold_font(&mut self, new_font: Option<&'static [u8]>) {
+        self.bold_font = new_font.map(|x| {
+            assert_eq!(x.len(), 256 * MAX_FONT_HEIGHT);
+            x.as_ptr()
+        });
+    }
+
+    /// Switch to monochrome output, for hardware with a single video pin.
+    /// Colours with a `luminance` of at least `threshold` are lit and the
+    /// rest are dark, and the pixels are sent to
+    /// `Hardware::write_mono_pixels`. A threshold of 1 lights every colour
+    /// but black. `None` goes back to colour output.
+    pub fn set_mono_output(&mut self, threshold: Option<u8>) {
+        self.mono = threshold.map(|threshold| {
+            COLOUR_LUMINANCE
+                .iter()
+                .enumerate()
+                .filter(|&(_, &luminance)| luminance >= threshold)
+                .fold(0, |lit, (index, _)| lit | (1 << index))
+        });
+    }
+
+    /// Turn on temporal dithering. Each `Colour` is shown as the first colour
+    /// of its pair in `palette` on one frame and the second on the next, so
+    /// you see a mix of the two. For example, `(Colour::Red, Colour::Yellow)`
+    /// looks orange and `(Colour::Black, Colour::White)` looks grey. Mixing
+    /// the 8 colours like this gives 27 shades (each of red, green and blue
+    /// can be off, half or full), 8 at a time.
+    ///
+    /// `palette` is indexed by colour value (`Colour::Black` is 0, up to
+    /// `NUM_COLOURS - 1`). Colours after the end of it are shown as they are,
+    /// as are colours given the same colour twice, and extra entries are
+    /// ignored.
+    ///
+    /// Alternate scan-lines use opposite phases, which cuts down the flicker.
+    /// It is still visible on some monitors. `None` turns dithering off.
+    pub fn set_dither_palette(&mut self, palette: Option<&[(Colour, Colour)]>) {
+        self.dither = palette.map(|palette| {
+            let mut evens = [0; NUM_COLOURS];
+            let mut odds = [0; NUM_COLOURS];
+            for (index, (even, odd)) in evens.iter_mut().zip(odds.iter_mut()).enumerate() {
+                let (first, second) = palette
+                    .get(index)
+                    .map_or((index as u8, index as u8), |&(first, second)| {
+                        (first as u8, second as u8)
+                    });
+                *even = first;
+                *odd = second;
+            }
+            [evens, odds]
+        });
+    }
+
+    /// Give the frame buffer a font in RAM and select it, so glyphs can be
+    /// changed with `define_glyph`. Returns the RAM font we had before, if
+    /// any. Passing `None` goes back to the default font.
+    ///
+    /// Selecting another font with `set_custom_font` keeps the RAM font, and
+    /// `define_glyph` still changes it, but it isn't shown until you give it
+    /// back to `set_ram_font`.
+    pub fn set_ram_font(
+        &mut self,
+        ram_font: Option<&'static mut RamFont>,
+    ) -> Option<&'static mut RamFont> {
+        let ram_font = ram_font.map(|f| f as *mut RamFont);
+        match ram_font {
+            Some(f) => self.font = Some(unsafe { ram_font::data_ptr(f) }),
+            None => self.set_custom_font(None),
+        }
+        // This came from a `&'static mut`, and we've finished with it
+        ::core::mem::replace(&mut self.ram_font, ram_font).map(|f| unsafe { &mut *f })
+    }
+
+    /// Change the glyph for one character in the RAM font (see
+    /// `set_ram_font`). Text already on the screen changes too. Returns
+    /// `false` if there's no RAM font.
+    pub fn define_glyph(&mut self, ch: Char, rows: [u8; MAX_FONT_HEIGHT]) -> bool {
+        match self.ram_font {
+            Some(f) => {
+                unsafe { ram_font::define_glyph_raw(f, ch, rows) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Change the current code page.
+    ///
+    /// The code page controls how Unicode text written with
+    /// `core::fmt::Write` is converted into 8-bit characters. It doesn't
+    /// change the font, so that only the fonts you use are linked in; select
+    /// the matching one with `set_custom_font`:
+    ///
+    /// ```ignore
+    /// fb.set_code_page(CodePage::Koi8R);
+    /// fb.set_custom_font(Some(&vga_framebuffer::koi8_r::FONT_DATA));
+    /// ```
+    ///
+    /// Text already on the screen is not converted, so it will be shown with
+    /// the new font's glyphs.
+    pub fn set_code_page(&mut self, code_page: CodePage) {
+        self.code_page = code_page;
+    }
+
+    /// Get the current code page.
+    pub fn get_code_page(&self) -> CodePage {
+        self.code_page
     }
 
     /// Clears the screen and resets the cursor to 0,0.
@@ -629,31 +1542,23 @@ where
                 *slot = (Char::Space, self.attr);
             }
             row.double_height = DoubleHeightMode::Normal;
+            row.font = FontSlot::Main;
         }
         self.pos = Position::origin();
     }
 
     /// Puts a glyph on screen at the specified place
     pub fn write_glyph_at(&mut self, glyph: Char, pos: Position, attr: Option<Attr>) {
-        if self.cursor_visible && (pos.row == self.pos.row) && (pos.col == self.pos.col) {
-            self.under_cursor = glyph;
-            self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize].1 =
-                attr.unwrap_or(self.attr);
-        } else if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
-            self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize] =
-                (glyph, attr.unwrap_or(self.attr));
+        if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
+            let row = self.buffer_row(pos.row.0 as usize);
+            self.text_buffer[row].glyphs[pos.col.0 as usize] = (glyph, attr.unwrap_or(self.attr));
         }
     }
 
     /// Read a glyph on screen at the specified place
     pub fn read_glyph_at(&mut self, pos: Position) -> Option<(Char, Attr)> {
-        if self.cursor_visible && (pos.row == self.pos.row) && (pos.col == self.pos.col) {
-            Some((
-                self.under_cursor,
-                self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize].1,
-            ))
-        } else if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
-            Some(self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize])
+        if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
+            Some(self.text_buffer[self.buffer_row(pos.row.0 as usize)].glyphs[pos.col.0 as usize])
         } else {
             None
         }
@@ -661,12 +1566,7 @@ where
 
     /// Puts a glyph on screen at the current position.
     pub fn write_glyph(&mut self, glyph: Char, attr: Option<Attr>) {
-        if self.cursor_visible {
-            self.under_cursor = glyph;
-            self.current_cell().1 = attr.unwrap_or(self.attr);
-        } else {
-            *self.current_cell() = (glyph, attr.unwrap_or(self.attr));
-        }
+        *self.current_cell() = (glyph, attr.unwrap_or(self.attr));
         self.move_cursor_right().unwrap();
     }
 
@@ -677,17 +1577,32 @@ where
 
     /// Changes the attribute for a given position, leaving the glyph unchanged.
     pub fn set_attr_at(&mut self, pos: Position, attr: Attr) {
-        self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize].1 = attr;
+        let row = self.buffer_row(pos.row.0 as usize);
+        self.text_buffer[row].glyphs[pos.col.0 as usize].1 = attr;
     }
 
     /// Change font height for a given line.
     pub fn set_line_mode_at(&mut self, row: Row, double_height: DoubleHeightMode) {
-        self.text_buffer[row.0 as usize].double_height = double_height;
+        let row = self.buffer_row(row.0 as usize);
+        self.text_buffer[row].double_height = double_height;
     }
 
     /// Change font height for the current line.
     pub fn set_line_mode(&mut self, double_height: DoubleHeightMode) {
-        self.text_buffer[self.pos.row.0 as usize].double_height = double_height;
+        let row = self.buffer_row(self.pos.row.0 as usize);
+        self.text_buffer[row].double_height = double_height;
+    }
+
+    /// Choose which font a given line is drawn in.
+    pub fn set_line_font_at(&mut self, row: Row, font: FontSlot) {
+        let row = self.buffer_row(row.0 as usize);
+        self.text_buffer[row].font = font;
+    }
+
+    /// Choose which font the current line is drawn in.
+    pub fn set_line_font(&mut self, font: FontSlot) {
+        let row = self.buffer_row(self.pos.row.0 as usize);
+        self.text_buffer[row].font = font;
     }
 
     /// Change the current character attribute
@@ -703,7 +1618,121 @@ where
     }
 
     fn current_cell(&mut self) -> &mut (Char, Attr) {
-        &mut self.text_buffer[self.pos.row.0 as usize].glyphs[self.pos.col.0 as usize]
+        let row = self.buffer_row(self.pos.row.0 as usize);
+        &mut self.text_buffer[row].glyphs[self.pos.col.0 as usize]
+    }
+
+    /// Act on a complete `ESC [` sequence ending in `command`.
+    fn handle_csi(&mut self, csi: &CsiEscape, command: u8) {
+        let params = &csi.params[..csi.count.min(CSI_MAX_PARAMS)];
+        match (csi.private, command) {
+            (false, b'm') => {
+                // No numbers means the same as 0
+                if params.is_empty() {
+                    self.attr = DEFAULT_ATTR;
+                }
+                for &param in params {
+                    self.select_graphic_rendition(param);
+                }
+            }
+            (true, b'h') if params.contains(&1049) => {
+                self.enter_alt_screen();
+            }
+            (true, b'l') if params.contains(&1049) => {
+                self.leave_alt_screen();
+            }
+            _ => {}
+        }
+    }
+
+    /// Save the screen for `ESC [ ? 1049 h`, and clear it.
+    fn enter_alt_screen(&mut self) {
+        if self.alt_screen_active {
+            return;
+        }
+        if let Some(saved) = self.alt_screen.take() {
+            self.save_screen(saved);
+            self.alt_screen = Some(saved);
+            self.alt_screen_active = true;
+            self.clear();
+        }
+    }
+
+    /// Put the screen back for `ESC [ ? 1049 l`.
+    fn leave_alt_screen(&mut self) {
+        if !self.alt_screen_active {
+            return;
+        }
+        if let Some(saved) = self.alt_screen.take() {
+            self.restore_screen(saved);
+            self.alt_screen = Some(saved);
+        }
+        self.alt_screen_active = false;
+    }
+
+    /// Handle one number from an `ESC [ ... m` sequence.
+    fn select_graphic_rendition(&mut self, param: u16) {
+        match param {
+            0 => {
+                self.attr = DEFAULT_ATTR;
+            }
+            1 => {
+                self.attr.set_bold(true);
+            }
+            22 => {
+                self.attr.set_bold(false);
+            }
+            30..=37 => {
+                self.attr.set_fg(ANSI_COLOURS[(param - 30) as usize]);
+            }
+            39 => {
+                self.attr.set_fg(DEFAULT_FG);
+            }
+            40..=47 => {
+                self.attr.set_bg(ANSI_COLOURS[(param - 40) as usize]);
+            }
+            49 => {
+                self.attr.set_bg(DEFAULT_BG);
+            }
+            90..=97 => {
+                self.attr.set_fg(ANSI_BRIGHT_COLOURS[(param - 90) as usize]);
+            }
+            100..=107 => {
+                self.attr
+                    .set_bg(ANSI_BRIGHT_COLOURS[(param - 100) as usize]);
+            }
+            _ => {}
+        }
+    }
+
+    /// Write a Unicode char in the current code page, transliterating it if
+    /// the code page doesn't have it. We try a second time on the
+    /// replacement, as it may use symbols this code page also lacks.
+    fn write_unicode(&mut self, ch: char) -> Result<(), ()> {
+        if let Ok(glyph) = self.code_page.try_map_char(ch) {
+            return self.write_character(glyph.to_byte());
+        }
+        match translit::transliterate(ch) {
+            Some(replacement) => {
+                for ch in replacement.chars() {
+                    match self.code_page.try_map_char(ch) {
+                        Ok(glyph) => self.write_character(glyph.to_byte())?,
+                        Err(_) => self.write_str_lossy(translit::transliterate(ch))?,
+                    }
+                }
+                Ok(())
+            }
+            None => self.write_character(Char::QuestionMark.to_byte()),
+        }
+    }
+
+    /// Write a transliterated replacement, with '?' for anything this code
+    /// page doesn't have.
+    fn write_str_lossy(&mut self, replacement: Option<&str>) -> Result<(), ()> {
+        for ch in replacement.unwrap_or("?").chars() {
+            self.write_character(self.code_page.map_char(ch).to_byte())?;
+        }
+        Ok(())
     }
 }
 
@@ -726,14 +1755,7 @@ where
     /// Set the horizontal position for the next text output.
     fn set_col(&mut self, col: Col) -> Result<(), Self::Error> {
         if col <= self.get_width() {
-            if self.cursor_visible {
-                self.current_cell().0 = self.under_cursor;
-                self.pos.col = col;
-                self.under_cursor = self.current_cell().0;
-                self.current_cell().0 = CURSOR;
-            } else {
-                self.pos.col = col;
-            }
+            self.pos.col = col;
             Ok(())
         } else {
             Err(())
@@ -743,14 +1765,7 @@ where
     /// Set the vertical position for the next text output.
     fn set_row(&mut self, row: Row) -> Result<(), Self::Error> {
         if row <= self.get_height() {
-            if self.cursor_visible {
-                self.current_cell().0 = self.under_cursor;
-                self.pos.row = row;
-                self.under_cursor = self.current_cell().0;
-                self.current_cell().0 = CURSOR;
-            } else {
-                self.pos.row = row;
-            }
+            self.pos.row = row;
             Ok(())
         } else {
             Err(())
@@ -760,14 +1775,7 @@ where
     /// Set the horizontal and vertical position for the next text output.
     fn set_pos(&mut self, pos: Position) -> Result<(), Self::Error> {
         if pos.row <= self.get_height() && pos.col <= self.get_width() {
-            if self.cursor_visible {
-                self.current_cell().0 = self.under_cursor;
-                self.pos = pos;
-                self.under_cursor = self.current_cell().0;
-                self.current_cell().0 = CURSOR;
-            } else {
-                self.pos = pos;
-            }
+            self.pos = pos;
             Ok(())
         } else {
             Err(())
@@ -801,15 +1809,14 @@ where
 
     /// Called when the screen needs to scroll up one row.
     fn scroll_screen(&mut self) -> Result<(), Self::Error> {
-        let old_cursor = self.cursor_visible;
-        self.set_cursor_visible(false);
-        for line in 0..MODE0_TEXT_NUM_ROWS - 1 {
-            self.text_buffer[line] = self.text_buffer[line + 1];
-        }
-        for slot in self.text_buffer[MODE0_TEXT_MAX_ROW].glyphs.iter_mut() {
-            *slot = (Char::Space, self.attr);
+        // The old top row becomes the new bottom row
+        let old_top = self.first_row;
+        match self.scrollback.as_mut() {
+            Some(s) if !self.alt_screen_active => s.push(&self.text_buffer[old_top]),
+            _ => {}
         }
-        self.set_cursor_visible(old_cursor);
+        self.first_row = self.buffer_row(1);
+        self.text_buffer[old_top] = Mode0TextRow::blank(self.attr);
         Ok(())
     }
 }
@@ -821,7 +1828,67 @@ where
     /// Handle an escape char.
     /// We take a, b, c, d, e, f, g, h as being a background colour and A..H as being a foreground colour.
     /// 'Z' means clear the screen.
+    ///
+    /// '=' defines a glyph in the RAM font, like `VDU 23` on a BBC Micro. It
+    /// is followed by 34 hex digits: two for the character, then two for
+    /// each of the 16 rows, from the top. So
+    /// `ESC = 80 000000183C7EFFFFFF7E3C1800000000` (without the spaces) makes
+    /// character 0x80 a ball. Anything other than a hex digit abandons the
+    /// sequence.
+    ///
+    /// '[' starts an ANSI control sequence. We understand Select Graphic
+    /// Rendition (`ESC [ ... m`): 0 resets the colours, 1 and 22 turn bold
+    /// on and off, 30-37 and 40-47 set the foreground and background colour,
+    /// 39 and 49 put them back to the default, and 90-97 and 100-107 set
+    /// bright colours (with the `rgbi` feature). `ESC [ ? 1049 h` and
+    /// `ESC [ ? 1049 l` switch to and from the alternate screen (see
+    /// `FrameBuffer::set_alt_screen`). Other sequences are ignored.
     fn handle_escape(&mut self, escaped_char: u8) -> bool {
+        if let Some(mut glyph) = self.glyph_escape.take() {
+            let nibble = match (escaped_char as char).to_digit(16) {
+                Some(n) => n as u8,
+                None => return true,
+            };
+            if glyph.digits < 2 {
+                glyph.ch = (glyph.ch << 4) | nibble;
+            } else {
+                let row = (glyph.digits - 2) / 2;
+                glyph.rows[row] = (glyph.rows[row] << 4) | nibble;
+            }
+            glyph.digits += 1;
+            if glyph.digits == 2 + (2 * MAX_FONT_HEIGHT) {
+                // Ignored if there's no RAM font
+                self.define_glyph(Char::from_byte(glyph.ch), glyph.rows);
+                return true;
+            }
+            self.glyph_escape = Some(glyph);
+            return false;
+        }
+        if let Some(mut csi) = self.csi_escape.take() {
+            match escaped_char {
+                b'0'..=b'9' => {
+                    csi.count = csi.count.max(1);
+                    if let Some(param) = csi.params.get_mut(csi.count - 1) {
+                        *param = param
+                            .saturating_mul(10)
+                            .saturating_add((escaped_char - b'0') as u16);
+                    }
+                }
+                b';' => {
+                    csi.count = csi.count.max(1) + 1;
+                }
+                b'?' if csi.count == 0 => {
+                    csi.private = true;
+                }
+                0x40..=0x7E => {
+                    self.handle_csi(&csi, escaped_char);
+                    return true;
+                }
+                _ => return true,
+            }
+            self.csi_escape = Some(csi);
+            return false;
+        }
         match escaped_char {
             b'W' => {
                 self.attr.set_fg(Colour::White);
@@ -883,21 +1950,34 @@ where
             b'Z' => {
                 self.clear();
             }
+            b'[' => {
+                self.csi_escape = Some(CsiEscape {
+                    params: [0; CSI_MAX_PARAMS],
+                    count: 0,
+                    private: false,
+                });
+                return false;
+            }
+            b'=' => {
+                self.glyph_escape = Some(GlyphEscape {
+                    digits: 0,
+                    ch: 0,
+                    rows: [0; MAX_FONT_HEIGHT],
+                });
+                return false;
+            }
             _ => {}
         }
-        // We only have single char sequences
+        // Everything else is a single char sequence
         true
     }
 
     /// Write a single Unicode char to the screen at the given position
     /// without updating the current position.
     fn write_char_at(&mut self, ch: u8, pos: Position) -> Result<(), Self::Error> {
-        if self.cursor_visible && (pos.row == self.pos.row) && (pos.col == self.pos.col) {
-            self.under_cursor = Char::from_byte(ch);
-            self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize].1 = self.attr;
-        } else if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
-            self.text_buffer[pos.row.0 as usize].glyphs[pos.col.0 as usize] =
-                (Char::from_byte(ch), self.attr);
+        if (pos.col <= self.get_width()) && (pos.row <= self.get_height()) {
+            let row = self.buffer_row(pos.row.0 as usize);
+            self.text_buffer[row].glyphs[pos.col.0 as usize] = (Char::from_byte(ch), self.attr);
         }
         Ok(())
     }
@@ -907,35 +1987,204 @@ impl<T> core::fmt::Write for FrameBuffer<T>
 where
     T: Hardware,
 {
+    /// Characters the current code page doesn't have are transliterated
+    /// (see the `translit` module) and combining accents are folded into the
+    /// letter before them where possible. An accent can only be combined with
+    /// a letter given in the same call.
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        for ch in s.chars() {
-            self.write_character(Char::map_char(ch) as u8)
-                .map_err(|_| core::fmt::Error)?;
+        let mut chars = s.chars().peekable();
+        while let Some(mut ch) = chars.next() {
+            if translit::is_combining(ch) {
+                // Nothing to attach it to
+                continue;
+            }
+            while let Some(&mark) = chars.peek() {
+                if !translit::is_combining(mark) {
+                    break;
+                }
+                chars.next();
+                if let Some(composed) = translit::compose(ch, mark) {
+                    ch = composed;
+                }
+            }
+            self.write_unicode(ch).map_err(|_| core::fmt::Error)?;
         }
         Ok(())
     }
 }
 
+#[cfg(not(feature = "rgbi"))]
 impl Attr {
+    const FG_SHIFT: u32 = 3;
     const FG_BITS: u8 = 0b0011_1000;
     const BG_BITS: u8 = 0b0000_0111;
+    #[cfg(not(feature = "compact_rgb"))]
+    const COLOUR_BITS: u8 = Self::FG_BITS | Self::BG_BITS;
+    const TRANSPARENT_BIT: u8 = 0b0100_0000;
+    const BOLD_BIT: u8 = 0b1000_0000;
+
+    /// The low 8 bits. Without the `rgbi` feature that's all of them; with
+    /// it, the colours are here and the other bits are in `as_u16`.
+    pub fn as_u8(self) -> u8 {
+        self.0
+    }
+
+    /// All the bits, which only need 8 without the `rgbi` feature.
+    pub fn as_u16(self) -> u16 {
+        u16::from(self.0)
+    }
+}
+
+#[cfg(feature = "rgbi")]
+impl Attr {
+    const FG_SHIFT: u32 = 4;
+    const FG_BITS: u16 = 0b0000_0000_1111_0000;
+    const BG_BITS: u16 = 0b0000_0000_0000_1111;
+    const TRANSPARENT_BIT: u16 = 0b0000_0001_0000_0000;
+    const BOLD_BIT: u16 = 0b0000_0010_0000_0000;
+
+    /// The low 8 bits. Without the `rgbi` feature that's all of them; with
+    /// it, the colours are here and the other bits are in `as_u16`.
+    pub fn as_u8(self) -> u8 {
+        self.0 as u8
+    }
 
+    /// All the bits, which only need 8 without the `rgbi` feature.
+    pub fn as_u16(self) -> u16 {
+        self.0
+    }
+}
+
+impl Attr {
     pub const fn new(fg: Colour, bg: Colour) -> Attr {
-        Attr(((fg as u8) << 3) + (bg as u8))
+        Attr(((fg as AttrBits) << Self::FG_SHIFT) + (bg as AttrBits))
     }
 
     pub fn set_fg(&mut self, fg: Colour) -> &mut Attr {
-        self.0 = ((fg as u8) << 3) + (self.0 & !Self::FG_BITS);
+        self.0 = ((fg as AttrBits) << Self::FG_SHIFT) + (self.0 & !Self::FG_BITS);
         self
     }
 
     pub fn set_bg(&mut self, bg: Colour) -> &mut Attr {
-        self.0 = (self.0 & !Self::BG_BITS) + (bg as u8);
+        self.0 = (self.0 & !Self::BG_BITS) + (bg as AttrBits);
         self
     }
 
-    pub fn as_u8(self) -> u8 {
-        self.0
+    /// Mark these colours as transparent. Text in a Mode 2 region with a
+    /// text overlay (see `FrameBuffer::mode2_region_overlay`) is then drawn
+    /// in the graphics' colours. It has no effect elsewhere.
+    pub fn set_transparent(&mut self, transparent: bool) -> &mut Attr {
+        if transparent {
+            self.0 |= Self::TRANSPARENT_BIT;
+        } else {
+            self.0 &= !Self::TRANSPARENT_BIT;
+        }
+        self
+    }
+
+    /// Are these colours marked as transparent?
+    pub const fn is_transparent(self) -> bool {
+        self.0 & Self::TRANSPARENT_BIT != 0
+    }
+
+    /// Make text in these colours bold.
+    pub fn set_bold(&mut self, bold: bool) -> &mut Attr {
+        if bold {
+            self.0 |= Self::BOLD_BIT;
+        } else {
+            self.0 &= !Self::BOLD_BIT;
+        }
+        self
+    }
+
+    /// Is text in these colours bold?
+    pub const fn is_bold(self) -> bool {
+        self.0 & Self::BOLD_BIT != 0
+    }
+
+    /// The foreground colour, as a number.
+    fn fg_index(self) -> u32 {
+        ((self.0 & Self::FG_BITS) >> Self::FG_SHIFT) as u32
+    }
+
+    /// The background colour, as a number.
+    fn bg_index(self) -> u32 {
+        (self.0 & Self::BG_BITS) as u32
+    }
+
+    /// Swap each colour for the one at its position in `colours`, keeping
+    /// the other bits.
+    fn with_colours(self, colours: &[u8; NUM_COLOURS]) -> Attr {
+        let fg = colours[self.fg_index() as usize] as AttrBits;
+        let bg = colours[self.bg_index() as usize] as AttrBits;
+        Attr((self.0 & !(Self::FG_BITS | Self::BG_BITS)) | (fg << Self::FG_SHIFT) | bg)
+    }
+}
+
+impl SavedScreen {
+    /// A blank screen. This is `const`, so it can be used to initialise a
+    /// `static mut` for `FrameBuffer::set_alt_screen`.
+    pub const fn new() -> SavedScreen {
+        SavedScreen {
+            rows: [Mode0TextRow::blank(DEFAULT_ATTR); MODE0_TEXT_NUM_ROWS],
+            pos: Position {
+                row: Row(0),
+                col: Col(0),
+            },
+            attr: DEFAULT_ATTR,
+            cursor_visible: true,
+        }
+    }
+}
+
+impl core::default::Default for SavedScreen {
+    fn default() -> Self {
+        SavedScreen::new()
+    }
+}
+
+impl core::default::Default for Mode2Layout {
+    /// The layout `Mode2Canvas` and the `image` module use - most
+    /// significant bit first, with no gaps between lines.
+    fn default() -> Self {
+        Mode2Layout {
+            bit_order: BitOrder::MsbFirst,
+            stride: MODE0_USABLE_HORIZONTAL_OCTETS,
+            offset: 0,
+        }
+    }
+}
+
+impl Tile {
+    pub const fn new(index: u8, attr: Attr) -> Tile {
+        Tile { index, attr }
+    }
+}
+
+impl Mode0TextRow {
+    /// A row of spaces, in the given colours. Useful for initialising a
+    /// scroll-back buffer:
+    ///
+    /// ```ignore
+    /// static mut HISTORY: [Mode0TextRow; 100] =
+    ///     [Mode0TextRow::blank(Attr::new(Colour::White, Colour::Blue)); 100];
+    /// ```
+    pub const fn blank(attr: Attr) -> Mode0TextRow {
+        Mode0TextRow {
+            double_height: DoubleHeightMode::Normal,
+            font: FontSlot::Main,
+            glyphs: [(Char::Space, attr); MODE0_TEXT_NUM_COLS],
+        }
+    }
+
+    /// Which font this row is drawn with.
+    pub fn font(&self) -> FontSlot {
+        self.font
+    }
+
+    /// Draw this row with the given font.
+    pub fn set_font(&mut self, font: FontSlot) {
+        self.font = font;
     }
 }
 
@@ -946,6 +2195,12 @@ impl core::default::Default for Attr {
 }
 
 impl Colour {
+    /// How bright this colour looks, from 0 (black) to 255 (white), using
+    /// the ITU-R BT.601 weights (30% red, 59% green, 11% blue).
+    pub fn luminance(self) -> u8 {
+        COLOUR_LUMINANCE[self as usize]
+    }
+
     /// Generate 8 pixels in RGB which are all this colour
     pub fn into_pixels(self) -> XRGBColour {
         match self {
@@ -957,6 +2212,22 @@ impl Colour {
             Colour::Green => XRGBColour::new(0x00, 0xFF, 0x00),
             Colour::Blue => XRGBColour::new(0x00, 0x00, 0xFF),
             Colour::Black => XRGBColour::new(0x00, 0x00, 0x00),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightWhite => XRGBColour::new_rgbi(0xFF, 0xFF, 0xFF, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightYellow => XRGBColour::new_rgbi(0xFF, 0xFF, 0x00, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightMagenta => XRGBColour::new_rgbi(0xFF, 0x00, 0xFF, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightRed => XRGBColour::new_rgbi(0xFF, 0x00, 0x00, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightCyan => XRGBColour::new_rgbi(0x00, 0xFF, 0xFF, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightGreen => XRGBColour::new_rgbi(0x00, 0xFF, 0x00, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::BrightBlue => XRGBColour::new_rgbi(0x00, 0x00, 0xFF, 0xFF),
+            #[cfg(feature = "rgbi")]
+            Colour::Grey => XRGBColour::new_rgbi(0x00, 0x00, 0x00, 0xFF),
         }
     }
 }
@@ -999,12 +2270,110 @@ impl XRGBColour {
     }
 }
 
+#[cfg(feature = "rgbi")]
+impl XRGBColour {
+    /// Create a new block of 8 coloured pixels like `new`, with 8
+    /// intensity pixels as well. A set intensity bit brightens the pixel.
+    pub const fn new_rgbi(red: u8, green: u8, blue: u8, intensity: u8) -> XRGBColour {
+        XRGBColour(
+            ((intensity as u32) << 24)
+                | ((red as u32) << 16)
+                | ((green as u32) << 8)
+                | (blue as u32),
+        )
+    }
+
+    /// Get the 8 intensity pixels in the bottom 8 bits
+    pub const fn intensity(self) -> u32 {
+        (self.0 >> 24) & 0xFF
+    }
+
+    /// Pixel must be in the range 0..7, where 0 is the rightmost pixel
+    pub const fn pixel_has_intensity(self, pixel: u8) -> bool {
+        ((self.0 >> (24 + (7 - pixel))) & 1) == 1
+    }
+}
+
 // ***************************************************************************
 //
 // Impl for Private Types
 //
 // ***************************************************************************
 
+impl TileMode {
+    /// Draw one scan-line of tiles.
+    fn render_line<T>(&self, line: usize, hw: &mut T, output: &Output)
+    where
+        T: Hardware,
+    {
+        let y = (line - self.start + self.scroll_y) % (self.map_height * self.tile_height);
+        let map_row = unsafe { self.map.add((y / self.tile_height) * self.map_width) };
+        let tiles = unsafe { self.tiles.add(y % self.tile_height) };
+        let tile_pixels =
+            |tile: Tile| unsafe { *tiles.add(tile.index as usize * self.tile_height) };
+
+        // If we're not on a tile boundary, each octet is made from the right
+        // hand side of one tile and the left hand side of the next. It can
+        // only have one set of colours, so it takes them from whichever has
+        // more pixels in the octet.
+        let fine = self.scroll_x % MAX_FONT_WIDTH;
+        let mut col = self.scroll_x / MAX_FONT_WIDTH;
+        let mut left = unsafe { *map_row.add(col) };
+        let octets = (0..MODE0_USABLE_HORIZONTAL_OCTETS).map(|_| {
+            col += 1;
+            if col == self.map_width {
+                col = 0;
+            }
+            let right = unsafe { *map_row.add(col) };
+            let octet = if fine == 0 {
+                (left.attr, tile_pixels(left))
+            } else {
+                let pixels = (tile_pixels(left) << fine) | (tile_pixels(right) >> (8 - fine));
+                let attr = if fine < 4 { left.attr } else { right.attr };
+                (attr, pixels)
+            };
+            left = right;
+            octet
+        });
+        write_octets(hw, output, octets);
+    }
+}
+
+impl Scrollback {
+    /// Store a row which has scrolled off the screen. If we're looking back
+    /// through the buffer, keep looking at the same rows.
+    fn push(&mut self, row: &Mode0TextRow) {
+        if self.rows.is_empty() {
+            return;
+        }
+        self.rows[self.next] = *row;
+        self.next = (self.next + 1) % self.rows.len();
+        self.len = core::cmp::min(self.len + 1, self.rows.len());
+        if self.offset != 0 {
+            self.offset = core::cmp::min(self.offset + 1, self.len);
+        }
+    }
+
+    /// Get a stored row, by how many rows ago it scrolled off (1 is the most
+    /// recent).
+    fn row(&self, age: usize) -> &Mode0TextRow {
+        &self.rows[(self.next + self.rows.len() - age) % self.rows.len()]
+    }
+}
+
+impl FontTables {
+    /// Move each table on to the given row of glyph 0.
+    fn add(self, font_row: usize) -> FontTables {
+        unsafe {
+            FontTables {
+                regular: self.regular.add(font_row),
+                #[cfg(feature = "bold")]
+                bold: self.bold.add(font_row),
+            }
+        }
+    }
+}
+
 // ***************************************************************************
 //
 // Public Functions
@@ -1017,6 +2386,695 @@ impl XRGBColour {
 //
 // ***************************************************************************
 
+/// Send octets of mono pixels to the hardware, each in the colours in its
+/// `Attr`. The output mode is only checked once, and each mode has its own
+/// loop, so plain colour output costs no more than it would without mono
+/// output and dithering.
+#[inline(always)]
+fn write_octets<T, I>(hw: &mut T, output: &Output, octets: I)
+where
+    T: Hardware,
+    I: Iterator<Item = (Attr, u8)>,
+{
+    match *output {
+        Output::Colour => {
+            for (attr, mono_pixels) in octets {
+                hw.write_pixels(mono_to_rgb(attr, mono_pixels));
+            }
+        }
+        Output::Dither(ref colours) => {
+            for (attr, mono_pixels) in octets {
+                hw.write_pixels(mono_to_rgb(attr.with_colours(colours), mono_pixels));
+            }
+        }
+        Output::Mono(lit, ref dither) => {
+            for (attr, mono_pixels) in octets {
+                let attr = match *dither {
+                    Some(ref colours) => attr.with_colours(colours),
+                    None => attr,
+                };
+                // 0xFF if the colour is lit, otherwise 0x00
+                let fg = 0u8.wrapping_sub(((lit >> attr.fg_index()) & 1) as u8);
+                let bg = 0u8.wrapping_sub(((lit >> attr.bg_index()) & 1) as u8);
+                hw.write_mono_pixels((mono_pixels & fg) | (!mono_pixels & bg));
+            }
+        }
+    }
+}
+
+/// Get 8 pixels from a Mode 2 buffer, with the left-most pixel in the most
+/// significant bit.
+#[inline(always)]
+fn mode2_octet(buffer: *const u8, offset: isize, bit_order: BitOrder) -> u8 {
+    let byte = unsafe { *buffer.offset(offset) };
+    match bit_order {
+        BitOrder::MsbFirst => byte,
+        BitOrder::LsbFirst => byte.reverse_bits(),
+    }
+}
+
+/// Send 8 pixels of border to the hardware.
+#[inline(always)]
+fn write_border<T>(hw: &mut T, mono: Option<u16>)
+where
+    T: Hardware,
+{
+    match mono {
+        None => hw.write_pixels(XRGBColour::new(0xFF, 0xFF, 0xFF)),
+        Some(_) => hw.write_mono_pixels(0xFF),
+    }
+}
+
+/// Get one row of a glyph from font tables which have been offset to the
+/// right row. Bold text is drawn from the bold table.
+#[cfg(feature = "bold")]
+#[inline(always)]
+fn glyph_row(fonts: FontTables, ch: Char, attr: Attr) -> u8 {
+    let index = (ch as isize) * (MAX_FONT_HEIGHT as isize);
+    let font_table = if attr.is_bold() {
+        fonts.bold
+    } else {
+        fonts.regular
+    };
+    unsafe { *font_table.offset(index) }
+}
+
+/// Get one row of a glyph from font tables which have been offset to the
+/// right row. Without the `bold` feature, bold text is drawn like any other.
+#[cfg(not(feature = "bold"))]
+#[inline(always)]
+fn glyph_row(fonts: FontTables, ch: Char, _attr: Attr) -> u8 {
+    let index = (ch as isize) * (MAX_FONT_HEIGHT as isize);
+    unsafe { *fonts.regular.offset(index) }
+}
+
+/// Convert 8 mono pixels into 8 RGB pixels, using the colours in `attr`.
+///
+/// RGB_MAPs is a lookup of (pixels, fg, bg) -> (r,g,b). Each row is 4 bytes.
+/// The row index is 0bFFFBBBPPPPPPPP, where F = foreground, B = background,
+/// P = 8-bit pixels.
+#[cfg(not(any(feature = "compact_rgb", feature = "rgbi")))]
+#[inline(always)]
+fn mono_to_rgb(attr: Attr, mono_pixels: u8) -> XRGBColour {
+    unsafe {
+        *RGB_MAPS
+            .as_ptr()
+            .offset((((attr.0 & Attr::COLOUR_BITS) as isize) * 256_isize) + (mono_pixels as isize))
+    }
+}
+
+/// Convert 8 mono pixels into 8 RGB pixels, using the colours in `attr`.
+///
+/// The pixels are copied into each of the R, G and B bytes. Set pixels then
+/// take the foreground colour, and clear pixels take the background colour.
+/// This gives the same result as `RGB_MAPS` without the 64 KiB table. With
+/// the `rgbi` feature the pixels go into the intensity plane too.
+#[cfg(any(feature = "compact_rgb", feature = "rgbi"))]
+#[inline(always)]
+fn mono_to_rgb(attr: Attr, mono_pixels: u8) -> XRGBColour {
+    let pixels = (mono_pixels as u32) * 0x01_01_01_01;
+    let fg = COLOUR_MASKS[attr.fg_index() as usize];
+    let bg = COLOUR_MASKS[attr.bg_index() as usize];
+    XRGBColour((pixels & fg) | (!pixels & bg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+    use std::boxed::Box;
+    use std::vec::Vec;
+
+    /// Keeps the pixels written for the last scan-line.
+    #[derive(Default)]
+    struct TestHardware {
+        pixels: Vec<u32>,
+        mono_pixels: Vec<u8>,
+    }
+
+    impl Hardware for TestHardware {
+        fn configure(&mut self, _mode_info: &ModeInfo) {}
+        fn vsync_on(&mut self) {}
+        fn vsync_off(&mut self) {}
+        fn write_pixels(&mut self, xrgb: XRGBColour) {
+            self.pixels.push(xrgb.0);
+        }
+        fn write_mono_pixels(&mut self, pixels: u8) {
+            self.mono_pixels.push(pixels);
+        }
+    }
+
+    fn frame_buffer() -> Box<FrameBuffer<TestHardware>> {
+        let mut fb = Box::new(FrameBuffer::new());
+        fb.init(TestHardware::default());
+        fb
+    }
+
+    /// Draw the given visible scan-line and get the hardware, holding the
+    /// pixels (including the two border octets).
+    fn render(fb: &mut FrameBuffer<TestHardware>, line: usize) -> &TestHardware {
+        {
+            let hw = fb.hw.as_mut().unwrap();
+            hw.pixels.clear();
+            hw.mono_pixels.clear();
+        }
+        fb.line_no.store(V_DATA_FIRST + line, Ordering::Relaxed);
+        fb.isr_sol();
+        fb.hw.as_ref().unwrap()
+    }
+
+    /// The colour pixels for one octet of text.
+    fn octet(attr: Attr, font_row: u8) -> u32 {
+        mono_to_rgb(attr, font_row).0
+    }
+
+    /// The font row for a character in the default font.
+    fn font_row(ch: Char, row: usize) -> u8 {
+        freebsd_cp850::FONT_DATA[(ch as usize * MAX_FONT_HEIGHT) + row]
+    }
+
+    fn glyph(fb: &mut FrameBuffer<TestHardware>, col: u8) -> (Char, Attr) {
+        fb.read_glyph_at(Position::new(Row(0), Col(col))).unwrap()
+    }
+
+    fn text(fb: &mut FrameBuffer<TestHardware>) -> [u8; 8] {
+        let mut text = [0; 8];
+        for (col, byte) in text.iter_mut().enumerate() {
+            *byte = glyph(fb, col as u8).0.to_byte();
+        }
+        text
+    }
+
+    #[test]
+    fn sgr_colours_and_bold() {
+        let mut fb = frame_buffer();
+        write!(fb, "\x1b[1;31;42mA\x1b[22;39mB\x1b[49;4mC").unwrap();
+        let mut bold = Attr::new(Colour::Red, Colour::Green);
+        bold.set_bold(true);
+        assert_eq!(glyph(&mut fb, 0), (Char::LatinCapitalLetterA, bold));
+        assert_eq!(
+            glyph(&mut fb, 1),
+            (
+                Char::LatinCapitalLetterB,
+                Attr::new(DEFAULT_FG, Colour::Green)
+            )
+        );
+        assert_eq!(glyph(&mut fb, 2), (Char::LatinCapitalLetterC, DEFAULT_ATTR));
+    }
+
+    #[test]
+    fn sgr_reset() {
+        let mut fb = frame_buffer();
+        write!(fb, "\x1b[1;35mA\x1b[0mB\x1b[1;36mC\x1b[mD").unwrap();
+        assert_eq!(glyph(&mut fb, 1).1, DEFAULT_ATTR);
+        assert_eq!(glyph(&mut fb, 3).1, DEFAULT_ATTR);
+        assert_eq!(fb.get_attr(), DEFAULT_ATTR);
+    }
+
+    #[test]
+    #[cfg(not(feature = "rgbi"))]
+    fn sgr_bright_without_rgbi() {
+        let mut fb = frame_buffer();
+        write!(fb, "\x1b[91;104mA").unwrap();
+        assert_eq!(glyph(&mut fb, 0).1, Attr::new(Colour::Red, Colour::Blue));
+    }
+
+    #[test]
+    #[cfg(feature = "rgbi")]
+    fn sgr_bright_with_rgbi() {
+        let mut fb = frame_buffer();
+        write!(fb, "\x1b[91;100mA").unwrap();
+        assert_eq!(
+            glyph(&mut fb, 0).1,
+            Attr::new(Colour::BrightRed, Colour::Grey)
+        );
+    }
+
+    #[test]
+    fn csi_is_not_printed() {
+        let mut fb = frame_buffer();
+        // Unknown commands, unknown parameters, too many parameters and
+        // numbers too big for a u16 are all swallowed
+        write!(
+            fb,
+            "A\x1b[2JB\x1b[38;5;196mC\x1b[1;2;3;4;5;6;7;8;9;10;31mD\x1b[99999999mE"
+        )
+        .unwrap();
+        assert_eq!(&text(&mut fb)[..5], b"ABCDE");
+        // Only the first `CSI_MAX_PARAMS` numbers count, so it's bold but
+        // not red
+        let mut bold = DEFAULT_ATTR;
+        bold.set_bold(true);
+        assert_eq!(glyph(&mut fb, 3).1, bold);
+    }
+
+    #[test]
+    fn alt_screen() {
+        let mut fb = frame_buffer();
+        // Ignored without somewhere to save the screen
+        write!(fb, "\x1b[?1049hA").unwrap();
+        assert_eq!(glyph(&mut fb, 0).0, Char::LatinCapitalLetterA);
+        fb.set_alt_screen(Some(Box::leak(Box::new(SavedScreen::new()))));
+        write!(fb, "\x1b[?1049hBC").unwrap();
+        assert_eq!(&text(&mut fb)[..3], b"BC ");
+        // Swapping the buffer keeps the saved screen
+        fb.set_alt_screen(Some(Box::leak(Box::new(SavedScreen::new()))));
+        write!(fb, "\x1b[?1049l").unwrap();
+        assert_eq!(&text(&mut fb)[..2], b"A ");
+        let pos = fb.get_pos();
+        assert_eq!((pos.row, pos.col), (Row(0), Col(1)));
+        // Taking the buffer away puts the screen back
+        write!(fb, "\x1b[?1049hBC").unwrap();
+        fb.set_alt_screen(None);
+        assert_eq!(&text(&mut fb)[..2], b"A ");
+    }
+
+    #[test]
+    fn ram_font() {
+        let mut fb = frame_buffer();
+        let font: &'static mut RamFont = Box::leak(Box::new(RamFont::new()));
+        assert!(fb.set_ram_font(Some(font)).is_none());
+        write!(fb, "A").unwrap();
+        // Render the glyph, change it, and render it again
+        for _ in 0..(V_DATA_FIRST + MAX_FONT_HEIGHT) {
+            fb.isr_sol();
+        }
+        assert!(fb.define_glyph(Char::LatinCapitalLetterA, [0xFF; MAX_FONT_HEIGHT]));
+        for _ in 0..(V_WHOLE_FRAME + MAX_FONT_HEIGHT) {
+            fb.isr_sol();
+        }
+        let font = fb.set_ram_font(None).unwrap();
+        assert_eq!(
+            font.glyph(Char::LatinCapitalLetterA),
+            [0xFF; MAX_FONT_HEIGHT]
+        );
+        assert!(!fb.define_glyph(Char::LatinCapitalLetterA, [0; MAX_FONT_HEIGHT]));
+    }
+
+    #[test]
+    fn mode2_layout_drops_short_attrs() {
+        static BUFFER: [u8; 480] = [0; 480];
+        // Enough for 5 lines
+        static ATTRS: [Attr; 240] = [DEFAULT_ATTR; 240];
+        let mut fb = frame_buffer();
+        fb.mode2(&BUFFER, 0);
+        fb.mode2_layout(Mode2Layout {
+            stride: 96,
+            ..Mode2Layout::default()
+        });
+        fb.mode2_region_attrs(0, &ATTRS, 1);
+        fb.mode2_layout(Mode2Layout {
+            stride: 96,
+            offset: 8,
+            ..Mode2Layout::default()
+        });
+        assert!(fb.mode2[0].unwrap().attrs.is_some());
+        // Back to 10 lines
+        fb.mode2_layout(Mode2Layout::default());
+        assert!(fb.mode2[0].unwrap().attrs.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mode2_layout_short_stride() {
+        static BUFFER: [u8; 480] = [0; 480];
+        let mut fb = frame_buffer();
+        fb.mode2(&BUFFER, 0);
+        fb.mode2_layout(Mode2Layout {
+            stride: 1,
+            ..Mode2Layout::default()
+        });
+    }
+
+    /// A row with the given character in its first column.
+    fn marked_row(ch: u8) -> Mode0TextRow {
+        let mut row = Mode0TextRow::blank(DEFAULT_ATTR);
+        row.glyphs[0].0 = Char::from_byte(ch);
+        row
+    }
+
+    #[test]
+    fn scrollback_push_and_row() {
+        let mut scrollback = Scrollback {
+            rows: Box::leak(Box::new([Mode0TextRow::blank(DEFAULT_ATTR); 3])),
+            next: 0,
+            len: 0,
+            offset: 0,
+        };
+        for ch in b"ABCD" {
+            scrollback.push(&marked_row(*ch));
+        }
+        // Only the last three fit, and 'A' has been overwritten
+        assert_eq!(scrollback.len, 3);
+        let ages: Vec<u8> = (1..=3)
+            .map(|age| scrollback.row(age).glyphs[0].0.to_byte())
+            .collect();
+        assert_eq!(ages, b"DCB");
+        // Looking back keeps looking at the same rows, up to the oldest
+        scrollback.offset = 2;
+        scrollback.push(&marked_row(b'E'));
+        assert_eq!(scrollback.offset, 3);
+        scrollback.push(&marked_row(b'F'));
+        assert_eq!(scrollback.offset, 3);
+        assert_eq!(scrollback.row(3).glyphs[0].0, Char::LatinCapitalLetterD);
+    }
+
+    #[test]
+    fn scrollback_empty_buffer() {
+        let mut scrollback = Scrollback {
+            rows: Box::leak(Box::new([])),
+            next: 0,
+            len: 0,
+            offset: 0,
+        };
+        scrollback.push(&marked_row(b'A'));
+        assert_eq!(scrollback.len, 0);
+    }
+
+    #[test]
+    fn scrollback_view() {
+        let mut fb = frame_buffer();
+        let rows = Box::leak(Box::new([Mode0TextRow::blank(DEFAULT_ATTR); 10]));
+        fb.set_scrollback(Some(rows));
+        // Fill the screen, and the newline after the last row scrolls four
+        // rows off the top
+        for ch in b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklm" {
+            writeln!(fb, "{}", *ch as char).unwrap();
+        }
+        assert_eq!(fb.get_scrollback_len(), 4);
+        fb.scrollback_up(100);
+        assert_eq!(fb.get_scrollback_offset(), 4);
+        // The top row of the screen is now the oldest stored row
+        let row = 4;
+        let pixels = render(&mut fb, row).pixels[1];
+        assert_eq!(
+            pixels,
+            octet(DEFAULT_ATTR, font_row(Char::LatinCapitalLetterA, row))
+        );
+        fb.scrollback_reset();
+        let pixels = render(&mut fb, row).pixels[1];
+        assert_eq!(
+            pixels,
+            octet(DEFAULT_ATTR, font_row(Char::LatinCapitalLetterE, row))
+        );
+    }
+
+    /// The colours for each column of the tile map.
+    fn tile_attr(col: usize) -> Attr {
+        if col.is_multiple_of(2) {
+            Attr::new(Colour::White, Colour::Blue)
+        } else {
+            Attr::new(Colour::Red, Colour::Black)
+        }
+    }
+
+    /// Tile `n` has `n` in every row. The map is 50 tiles wide and 2 high,
+    /// and each tile is its column number (plus 100 on the second row).
+    fn tile_frame_buffer() -> Box<FrameBuffer<TestHardware>> {
+        let tiles: Vec<u8> = (0..=255u8).flat_map(|n| [n; 8]).collect();
+        let map: Vec<Tile> = (0..100)
+            .map(|idx| Tile {
+                index: ((idx / 50) * 100 + (idx % 50)) as u8,
+                attr: tile_attr(idx % 50),
+            })
+            .collect();
+        let mut fb = frame_buffer();
+        fb.tile_mode(
+            Box::leak(tiles.into_boxed_slice()),
+            8,
+            Box::leak(map.into_boxed_slice()),
+            50,
+        );
+        fb
+    }
+
+    /// The octets for the given scan-line, without the borders.
+    fn octets(fb: &mut FrameBuffer<TestHardware>, line: usize) -> Vec<u32> {
+        let pixels = &render(fb, line).pixels;
+        pixels[1..pixels.len() - 1].to_vec()
+    }
+
+    #[test]
+    fn tile_mode() {
+        let mut fb = tile_frame_buffer();
+        let expected: Vec<u32> = (0..48)
+            .map(|col| octet(tile_attr(col), col as u8))
+            .collect();
+        assert_eq!(octets(&mut fb, 0), expected);
+        assert_eq!(octets(&mut fb, 7), expected);
+        // The map wraps around at the bottom
+        assert_eq!(octets(&mut fb, 16), expected);
+        let expected: Vec<u32> = (0..48)
+            .map(|col| octet(tile_attr(col), 100 + col as u8))
+            .collect();
+        assert_eq!(octets(&mut fb, 8), expected);
+        // And at the right hand side
+        fb.tile_mode_scroll(45 * 8, 8);
+        let expected: Vec<u32> = (0..48)
+            .map(|col| (col + 45) % 50)
+            .map(|col| octet(tile_attr(col), 100 + col as u8))
+            .collect();
+        assert_eq!(octets(&mut fb, 0), expected);
+    }
+
+    #[test]
+    fn tile_mode_fine_scroll() {
+        let mut fb = tile_frame_buffer();
+        // Each octet takes its colours from whichever tile has more pixels
+        // in it
+        for &fine in [3, 4].iter() {
+            fb.tile_mode_scroll(80 + fine, 0);
+            let expected: Vec<u32> = (10..58)
+                .map(|col| {
+                    let (left, right) = (col % 50, (col + 1) % 50);
+                    let attr = tile_attr(if fine < 4 { left } else { right });
+                    octet(attr, ((left as u8) << fine) | ((right as u8) >> (8 - fine)))
+                })
+                .collect();
+            assert_eq!(octets(&mut fb, 0), expected);
+        }
+    }
+
+    #[test]
+    fn tile_mode_lines() {
+        let mut fb = tile_frame_buffer();
+        fb.tile_mode_lines(8, 16);
+        // Text above and below
+        let blank = [octet(DEFAULT_ATTR, 0); 48];
+        assert_eq!(octets(&mut fb, 7), blank);
+        assert_eq!(octets(&mut fb, 16), blank);
+        // The tiles start from the top of the map
+        assert_eq!(octets(&mut fb, 8)[1], octet(tile_attr(1), 1));
+        fb.tile_mode_release();
+        assert_eq!(octets(&mut fb, 8), blank);
+    }
+
+    /// Write an 'A' in each of the given colours along the top row.
+    fn write_a(fb: &mut FrameBuffer<TestHardware>, attrs: &[Attr]) {
+        for attr in attrs {
+            fb.set_attr(*attr);
+            write!(fb, "A").unwrap();
+        }
+    }
+
+    #[test]
+    fn mono_output() {
+        let mut fb = frame_buffer();
+        write_a(
+            &mut fb,
+            &[
+                Attr::new(Colour::White, Colour::Black),
+                Attr::new(Colour::Blue, Colour::Yellow),
+                Attr::new(Colour::Yellow, Colour::White),
+                Attr::new(Colour::Blue, Colour::Black),
+            ],
+        );
+        // White and yellow are lit, blue and black aren't
+        fb.set_mono_output(Some(100));
+        let pixels = font_row(Char::LatinCapitalLetterA, 8);
+        let hw = render(&mut fb, 8);
+        assert!(hw.pixels.is_empty());
+        assert_eq!(hw.mono_pixels.len(), MODE0_USABLE_HORIZONTAL_OCTETS + 2);
+        assert_eq!(hw.mono_pixels[0], 0xFF);
+        assert_eq!(&hw.mono_pixels[1..5], &[pixels, !pixels, 0xFF, 0x00]);
+        assert_eq!(hw.mono_pixels[MODE0_USABLE_HORIZONTAL_OCTETS + 1], 0xFF);
+        // And back to colour
+        fb.set_mono_output(None);
+        let hw = render(&mut fb, 8);
+        assert!(hw.mono_pixels.is_empty());
+        assert_eq!(
+            hw.pixels[1],
+            octet(Attr::new(Colour::White, Colour::Black), pixels)
+        );
+    }
+
+    #[test]
+    fn mono_output_threshold() {
+        let mut fb = frame_buffer();
+        write_a(&mut fb, &[Attr::new(Colour::Blue, Colour::Black)]);
+        // A threshold of 1 lights everything but black
+        fb.set_mono_output(Some(1));
+        let pixels = font_row(Char::LatinCapitalLetterA, 8);
+        assert_eq!(render(&mut fb, 8).mono_pixels[1], pixels);
+        // And 0 lights everything
+        fb.set_mono_output(Some(0));
+        assert_eq!(render(&mut fb, 8).mono_pixels[1], 0xFF);
+    }
+
+    #[test]
+    fn mono_output_tiles() {
+        let mut fb = tile_frame_buffer();
+        fb.set_mono_output(Some(100));
+        // White on blue, then red on black
+        let hw = render(&mut fb, 0);
+        assert!(hw.pixels.is_empty());
+        assert_eq!(&hw.mono_pixels[1..4], &[0x00, 0x00, 0x02]);
+    }
+
+    #[test]
+    fn dither_phase() {
+        let mut fb = frame_buffer();
+        let attr = Attr::new(Colour::Red, Colour::Black);
+        write_a(&mut fb, &[attr]);
+        // Black is shown as black then white, red as yellow then red
+        fb.set_dither_palette(Some(&[
+            (Colour::Black, Colour::White),
+            (Colour::Blue, Colour::Blue),
+            (Colour::Green, Colour::Green),
+            (Colour::Cyan, Colour::Cyan),
+            (Colour::Yellow, Colour::Red),
+        ]));
+        let first = octet(
+            Attr::new(Colour::Yellow, Colour::Black),
+            font_row(Char::LatinCapitalLetterA, 8),
+        );
+        let second = octet(
+            Attr::new(Colour::Red, Colour::White),
+            font_row(Char::LatinCapitalLetterA, 9),
+        );
+        assert_eq!(render(&mut fb, 8).pixels[1], first);
+        // Alternate lines have opposite phases...
+        assert_eq!(render(&mut fb, 9).pixels[1], second);
+        // ...which swap over each frame
+        fb.frame = 1;
+        let first = octet(
+            Attr::new(Colour::Red, Colour::White),
+            font_row(Char::LatinCapitalLetterA, 8),
+        );
+        assert_eq!(render(&mut fb, 8).pixels[1], first);
+        fb.frame = usize::MAX;
+        assert_eq!(render(&mut fb, 8).pixels[1], first);
+    }
+
+    #[test]
+    fn dither_short_palette() {
+        let mut fb = frame_buffer();
+        let attr = Attr::new(Colour::White, Colour::Magenta);
+        write_a(&mut fb, &[attr]);
+        // Colours past the end of the palette are shown as themselves
+        fb.set_dither_palette(Some(&[(Colour::Black, Colour::White)]));
+        let pixels = octet(attr, font_row(Char::LatinCapitalLetterA, 8));
+        assert_eq!(render(&mut fb, 8).pixels[1], pixels);
+        fb.set_dither_palette(None);
+        assert_eq!(render(&mut fb, 8).pixels[1], pixels);
+    }
+
+    #[test]
+    fn dither_mono_output() {
+        let mut fb = frame_buffer();
+        write_a(&mut fb, &[Attr::new(Colour::Black, Colour::Black)]);
+        // Dithering happens before the threshold
+        fb.set_dither_palette(Some(&[(Colour::Black, Colour::White)]));
+        fb.set_mono_output(Some(100));
+        assert_eq!(render(&mut fb, 8).mono_pixels[1], 0x00);
+        assert_eq!(render(&mut fb, 9).mono_pixels[1], 0xFF);
+    }
+
+    #[test]
+    fn cursor_cell() {
+        let mut fb = frame_buffer();
+        write_a(&mut fb, &[DEFAULT_ATTR]);
+        fb.set_cursor_visible(true);
+        fb.set_cursor_style(CursorStyle::Block);
+        let cursor_attr = Attr::new(Colour::Black, Colour::Yellow);
+        fb.set_cursor_attr(Some(cursor_attr));
+        // The cursor is after the 'A', and inverts the space there
+        let pixels = octets(&mut fb, 8);
+        assert_eq!(pixels.len(), MODE0_USABLE_HORIZONTAL_OCTETS);
+        let a = font_row(Char::LatinCapitalLetterA, 8);
+        assert_eq!(
+            &pixels[..3],
+            &[
+                octet(DEFAULT_ATTR, a),
+                octet(cursor_attr, 0xFF),
+                octet(DEFAULT_ATTR, 0)
+            ]
+        );
+        // In the last column
+        fb.set_pos(Position::new(Row(0), Col(47))).unwrap();
+        let pixels = octets(&mut fb, 8);
+        assert_eq!(pixels.len(), MODE0_USABLE_HORIZONTAL_OCTETS);
+        assert_eq!(pixels[46], octet(DEFAULT_ATTR, 0));
+        assert_eq!(pixels[47], octet(cursor_attr, 0xFF));
+        // And not on other rows
+        assert_eq!(octets(&mut fb, 24)[47], octet(DEFAULT_ATTR, 0));
+    }
+
+    #[test]
+    fn bold_font_is_smeared() {
+        for (index, (&regular, &bold)) in freebsd_cp850::FONT_DATA
+            .iter()
+            .zip(freebsd_cp850_bold::FONT_DATA.iter())
+            .enumerate()
+        {
+            assert_eq!(bold, regular | (regular >> 1), "byte {}", index);
+        }
+    }
+
+    #[cfg(feature = "bold")]
+    #[test]
+    fn bold_text() {
+        let mut fb = frame_buffer();
+        fb.set_cursor_visible(false);
+        let mut bold = DEFAULT_ATTR;
+        bold.set_bold(true);
+        write_a(&mut fb, &[bold, DEFAULT_ATTR]);
+        let bold_row = freebsd_cp850_bold::FONT_DATA
+            [(Char::LatinCapitalLetterA as usize * MAX_FONT_HEIGHT) + 8];
+        let pixels = octets(&mut fb, 8);
+        assert_eq!(pixels[0], octet(bold, bold_row));
+        assert_eq!(
+            pixels[1],
+            octet(DEFAULT_ATTR, font_row(Char::LatinCapitalLetterA, 8))
+        );
+        // Other fonts have no bold version unless one is given
+        static FONT: [u8; 256 * MAX_FONT_HEIGHT] = [0x10; 256 * MAX_FONT_HEIGHT];
+        static BOLD_FONT: [u8; 256 * MAX_FONT_HEIGHT] = [0x18; 256 * MAX_FONT_HEIGHT];
+        fb.set_custom_font(Some(&FONT));
+        assert_eq!(octets(&mut fb, 8)[0], octet(bold, 0x10));
+        fb.set_bold_font(Some(&BOLD_FONT));
+        let pixels = octets(&mut fb, 8);
+        assert_eq!(pixels[0], octet(bold, 0x18));
+        assert_eq!(pixels[1], octet(DEFAULT_ATTR, 0x10));
+    }
+
+    #[cfg(not(feature = "bold"))]
+    #[test]
+    fn bold_text() {
+        let mut fb = frame_buffer();
+        fb.set_cursor_visible(false);
+        let mut bold = DEFAULT_ATTR;
+        bold.set_bold(true);
+        write_a(&mut fb, &[bold]);
+        // Stored, but drawn like any other text
+        assert_eq!(glyph(&mut fb, 0).1, bold);
+        assert_eq!(
+            octets(&mut fb, 8)[0],
+            octet(bold, font_row(Char::LatinCapitalLetterA, 8))
+        );
+    }
+}
+
 // ***************************************************************************
 //
 // End of File
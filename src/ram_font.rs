@@ -0,0 +1,97 @@
+//! A font in RAM, so glyphs can be redefined while the program runs.
+//!
+//! This lets you make your own icons and graphics characters, like the
+//! user-defined characters on a BBC Micro. Give the font to
+//! `FrameBuffer::set_ram_font`, then change glyphs with
+//! `FrameBuffer::define_glyph` (or the `ESC =` sequence - see
+//! `FrameBuffer::handle_escape`).
+//!
+//! Each glyph is 16 bytes, one per row from the top, with the most
+//! significant bit of each byte being the left-most pixel.
+
+use core::ops::RangeInclusive;
+use core::ptr;
+
+use super::{freebsd_cp850, Char, MAX_FONT_HEIGHT};
+
+/// A complete 256 character font which can be changed. See the module
+/// documentation.
+pub struct RamFont {
+    data: [u8; 256 * MAX_FONT_HEIGHT],
+}
+
+impl RamFont {
+    /// A font where every glyph is blank. This is `const`, so it can be used
+    /// to initialise a `static mut`. Use `copy_from` to fill it in.
+    pub const fn blank() -> RamFont {
+        RamFont {
+            data: [0; 256 * MAX_FONT_HEIGHT],
+        }
+    }
+
+    /// A copy of the bundled Code Page 850 font.
+    pub fn new() -> RamFont {
+        RamFont {
+            data: freebsd_cp850::FONT_DATA,
+        }
+    }
+
+    /// Copy the glyphs for the given range of characters from another font
+    /// (e.g. `freebsd_teletext::FONT_DATA`), leaving the rest alone.
+    ///
+    /// Panics if `font` isn't `256 * 16` bytes long.
+    pub fn copy_from(&mut self, font: &[u8], chars: RangeInclusive<u8>) {
+        assert_eq!(font.len(), self.data.len());
+        let start = *chars.start() as usize * MAX_FONT_HEIGHT;
+        let end = (*chars.end() as usize + 1) * MAX_FONT_HEIGHT;
+        if start < end {
+            self.data[start..end].copy_from_slice(&font[start..end]);
+        }
+    }
+
+    /// Change the glyph for one character.
+    pub fn define_glyph(&mut self, ch: Char, rows: [u8; MAX_FONT_HEIGHT]) {
+        let start = ch.to_byte() as usize * MAX_FONT_HEIGHT;
+        self.data[start..start + MAX_FONT_HEIGHT].copy_from_slice(&rows);
+    }
+
+    /// Get the glyph for one character.
+    pub fn glyph(&self, ch: Char) -> [u8; MAX_FONT_HEIGHT] {
+        let start = ch.to_byte() as usize * MAX_FONT_HEIGHT;
+        let mut rows = [0; MAX_FONT_HEIGHT];
+        rows.copy_from_slice(&self.data[start..start + MAX_FONT_HEIGHT]);
+        rows
+    }
+
+    /// Get the whole font, in the same layout as the bundled fonts.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Where the glyphs in `font` start, for the renderer. Taking this from a raw
+/// pointer (rather than through `as_slice`) means that writes made with
+/// `define_glyph_raw` on the same pointer don't invalidate it.
+///
+/// `font` must be valid for as long as the result is used.
+pub unsafe fn data_ptr(font: *mut RamFont) -> *const u8 {
+    ptr::addr_of_mut!((*font).data) as *const u8
+}
+
+/// Change the glyph for one character, like `RamFont::define_glyph`, while
+/// the renderer may be reading the font through `data_ptr`.
+///
+/// `font` must be valid, and not otherwise borrowed.
+pub unsafe fn define_glyph_raw(font: *mut RamFont, ch: Char, rows: [u8; MAX_FONT_HEIGHT]) {
+    let start = ch.to_byte() as usize * MAX_FONT_HEIGHT;
+    let data = ptr::addr_of_mut!((*font).data) as *mut u8;
+    ptr::copy_nonoverlapping(rows.as_ptr(), data.add(start), MAX_FONT_HEIGHT);
+}
+
+impl Default for RamFont {
+    fn default() -> RamFont {
+        RamFont::new()
+    }
+}
+
+// End of file
@@ -0,0 +1,162 @@
+//! Transliteration of Unicode text that a code page can't show.
+//!
+//! `CodePage::map_char` turns anything it doesn't have a glyph for into '?'.
+//! This module provides a fallback which swaps such characters for the
+//! nearest thing we can draw - smart quotes become ASCII quotes, dashes
+//! become '-', an accented letter loses its accent and so on - and for
+//! combining accents to be folded into the letter before them.
+
+/// All the printable ASCII characters, so we can hand out single-character
+/// `&'static str` values without a table of them.
+static PRINTABLE: &str =
+    " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// The unaccented ASCII letter for each of U+00C0..U+017F, or 0 if there
+/// isn't one (see `transliterate`).
+static LATIN_BASE: [u8; 192] = [
+    b'A', b'A', b'A', b'A', b'A', b'A', 0, b'C', b'E', b'E', b'E', b'E', b'I', b'I', b'I', b'I',
+    b'D', b'N', b'O', b'O', b'O', b'O', b'O', b'x', b'O', b'U', b'U', b'U', b'U', b'Y', 0, 0, b'a',
+    b'a', b'a', b'a', b'a', b'a', 0, b'c', b'e', b'e', b'e', b'e', b'i', b'i', b'i', b'i', b'd',
+    b'n', b'o', b'o', b'o', b'o', b'o', b'/', b'o', b'u', b'u', b'u', b'u', b'y', 0, b'y', b'A',
+    b'a', b'A', b'a', b'A', b'a', b'C', b'c', b'C', b'c', b'C', b'c', b'C', b'c', b'D', b'd', b'D',
+    b'd', b'E', b'e', b'E', b'e', b'E', b'e', b'E', b'e', b'E', b'e', b'G', b'g', b'G', b'g', b'G',
+    b'g', b'G', b'g', b'H', b'h', b'H', b'h', b'I', b'i', b'I', b'i', b'I', b'i', b'I', b'i', b'I',
+    b'i', 0, 0, b'J', b'j', b'K', b'k', b'k', b'L', b'l', b'L', b'l', b'L', b'l', b'L', b'l', b'L',
+    b'l', b'N', b'n', b'N', b'n', b'N', b'n', 0, b'N', b'n', b'O', b'o', b'O', b'o', b'O', b'o', 0,
+    0, b'R', b'r', b'R', b'r', b'R', b'r', b'S', b's', b'S', b's', b'S', b's', b'S', b's', b'T',
+    b't', b'T', b't', b'T', b't', b'U', b'u', b'U', b'u', b'U', b'u', b'U', b'u', b'U', b'u', b'U',
+    b'u', b'W', b'w', b'Y', b'y', b'Y', b'Z', b'z', b'Z', b'z', b'Z', b'z', b's',
+];
+
+/// The combining accent (as an offset from U+0300) which, added to
+/// `LATIN_BASE`, makes each of U+00C0..U+017F. 0xFF means the letter doesn't
+/// decompose.
+static LATIN_MARK: [u8; 192] = [
+    0x00, 0x01, 0x02, 0x03, 0x08, 0x0A, 0xFF, 0x27, 0x00, 0x01, 0x02, 0x08, 0x00, 0x01, 0x02, 0x08,
+    0xFF, 0x03, 0x00, 0x01, 0x02, 0x03, 0x08, 0xFF, 0xFF, 0x00, 0x01, 0x02, 0x08, 0x01, 0xFF, 0xFF,
+    0x00, 0x01, 0x02, 0x03, 0x08, 0x0A, 0xFF, 0x27, 0x00, 0x01, 0x02, 0x08, 0x00, 0x01, 0x02, 0x08,
+    0xFF, 0x03, 0x00, 0x01, 0x02, 0x03, 0x08, 0xFF, 0xFF, 0x00, 0x01, 0x02, 0x08, 0x01, 0xFF, 0x08,
+    0x04, 0x04, 0x06, 0x06, 0x28, 0x28, 0x01, 0x01, 0x02, 0x02, 0x07, 0x07, 0x0C, 0x0C, 0x0C, 0x0C,
+    0xFF, 0xFF, 0x04, 0x04, 0x06, 0x06, 0x07, 0x07, 0x28, 0x28, 0x0C, 0x0C, 0x02, 0x02, 0x06, 0x06,
+    0x07, 0x07, 0x27, 0x27, 0x02, 0x02, 0xFF, 0xFF, 0x03, 0x03, 0x04, 0x04, 0x06, 0x06, 0x28, 0x28,
+    0x07, 0xFF, 0xFF, 0xFF, 0x02, 0x02, 0x27, 0x27, 0xFF, 0x01, 0x01, 0x27, 0x27, 0x0C, 0x0C, 0xFF,
+    0xFF, 0xFF, 0xFF, 0x01, 0x01, 0x27, 0x27, 0x0C, 0x0C, 0xFF, 0xFF, 0xFF, 0x04, 0x04, 0x06, 0x06,
+    0x0B, 0x0B, 0xFF, 0xFF, 0x01, 0x01, 0x27, 0x27, 0x0C, 0x0C, 0x01, 0x01, 0x02, 0x02, 0x27, 0x27,
+    0x0C, 0x0C, 0x27, 0x27, 0x0C, 0x0C, 0xFF, 0xFF, 0x03, 0x03, 0x04, 0x04, 0x06, 0x06, 0x0A, 0x0A,
+    0x0B, 0x0B, 0x28, 0x28, 0x02, 0x02, 0x02, 0x02, 0x08, 0x01, 0x01, 0x07, 0x07, 0x0C, 0x0C, 0xFF,
+];
+
+/// Is this a combining accent, which modifies the character before it?
+pub fn is_combining(ch: char) -> bool {
+    ('\u{0300}'..='\u{036f}').contains(&ch)
+}
+
+/// Combine a letter with a following combining accent (U+0300..U+036F) to
+/// make a precomposed letter, e.g. 'e' and U+0301 make 'é'. Only the Latin-1
+/// and Latin Extended-A letters are supported.
+pub fn compose(base: char, mark: char) -> Option<char> {
+    if !base.is_ascii_alphabetic() || !is_combining(mark) {
+        return None;
+    }
+    let mark = (mark as u32 - 0x300) as u8;
+    LATIN_BASE
+        .iter()
+        .zip(LATIN_MARK.iter())
+        .position(|(&b, &m)| b == base as u8 && m == mark)
+        .and_then(|idx| ::core::char::from_u32(0xC0 + idx as u32))
+}
+
+/// Find a look-alike for a character, for use when the code page doesn't
+/// have it. Returns `None` if we don't know of one.
+///
+/// The replacement may be several characters (e.g. "..." for '…') or none
+/// at all (for zero-width spaces). It is ASCII where possible, but some
+/// replacements are symbols that Code Page 850 has and other code pages
+/// may not (e.g. '☺' for '😀'), so you may need to transliterate the
+/// replacement too.
+pub fn transliterate(ch: char) -> Option<&'static str> {
+    let replacement = match ch {
+        // Quotation marks and apostrophes
+        '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}' | '\u{2032}' | '\u{02bc}'
+        | '\u{00b4}' => "'",
+        '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{201f}' | '\u{2033}' => "\"",
+        '\u{2039}' => "<",
+        '\u{203a}' => ">",
+        '\u{00ab}' => "<<",
+        '\u{00bb}' => ">>",
+        // Hyphens, dashes and minus signs
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{00ad}' => "-",
+        '\u{2026}' => "...",
+        // Spaces of various widths
+        '\u{00a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => " ",
+        // Zero-width spaces and joiners, and the byte order mark
+        '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}' => "",
+        // Punctuation from Latin-1, for code pages without it
+        '\u{00a1}' => "!",
+        '\u{00bf}' => "?",
+        '\u{00a6}' => "|",
+        '\u{00a9}' => "(c)",
+        '\u{00ae}' => "(R)",
+        '\u{00b0}' => "o",
+        '\u{00b1}' => "+-",
+        '\u{00b7}' | '\u{2219}' => ".",
+        '\u{00d7}' => "x",
+        '\u{00f7}' | '\u{2044}' | '\u{2215}' => "/",
+        '\u{00b5}' => "u",
+        '\u{00b9}' => "1",
+        '\u{00b2}' => "2",
+        '\u{00b3}' => "3",
+        '\u{00bc}' => "1/4",
+        '\u{00bd}' => "1/2",
+        '\u{00be}' => "3/4",
+        // Letters that don't decompose into a letter and an accent
+        '\u{00c6}' => "AE",
+        '\u{00e6}' => "ae",
+        '\u{00de}' => "Th",
+        '\u{00fe}' => "th",
+        '\u{00df}' => "ss",
+        '\u{0132}' => "IJ",
+        '\u{0133}' => "ij",
+        '\u{0149}' => "'n",
+        '\u{0152}' => "OE",
+        '\u{0153}' => "oe",
+        '\u{0192}' => "f",
+        '\u{0218}' => "S",
+        '\u{0219}' => "s",
+        '\u{021a}' => "T",
+        '\u{021b}' => "t",
+        '\u{03bc}' => "\u{00b5}",
+        // Other symbols
+        '\u{20ac}' => "EUR",
+        '\u{2122}' => "TM",
+        '\u{2030}' => "%.",
+        '\u{2217}' | '\u{2605}' | '\u{2606}' => "*",
+        '\u{2248}' => "~",
+        '\u{2260}' => "!=",
+        '\u{2264}' => "<=",
+        '\u{2265}' => ">=",
+        '\u{21d0}' => "<=",
+        '\u{21d2}' => "=>",
+        '\u{21d4}' => "<=>",
+        '\u{2713}' | '\u{2714}' => "v",
+        '\u{2717}' | '\u{2718}' => "x",
+        '\u{2764}' | '\u{1f499}'..='\u{1f49c}' => "\u{2665}",
+        // Emoji
+        '\u{1f609}' => ";)",
+        '\u{1f600}'..='\u{1f60a}' | '\u{1f642}' | '\u{1f60d}' => "\u{263a}",
+        '\u{2639}' | '\u{1f61e}' | '\u{1f641}' => ":(",
+        // Latin letters with accents lose the accent
+        '\u{00c0}'..='\u{017f}' => {
+            let base = LATIN_BASE[ch as usize - 0xC0];
+            if base == 0 {
+                return None;
+            }
+            let idx = (base - b' ') as usize;
+            &PRINTABLE[idx..=idx]
+        }
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+// End of file